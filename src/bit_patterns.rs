@@ -8,13 +8,14 @@
 //! Each pair (x, y) represents the following:
 //! - x: The bit that states if the cell has been set
 //! - y: The bit that states which player set the cell
+//!
 //! Because of this, if x = 0, y != 1. But if x == 1, y = (0, 1).
 
 mod cell;
 mod win_pattern;
 
 /// Represents the state of a particular cell.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Cell {
     TopLeft = 0b10_0000_0000_0000_0000,
     TopCentre = 0b00_1000_0000_0000_0000,
@@ -30,7 +31,7 @@ pub enum Cell {
 /// These patterns represent every possible win state.
 ///
 /// Rows, Columns and Diagonals are accounted for in this.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum WinPattern {
     TopRow = 0b10_1010_0000_0000_0000,
     CentreRow = 0b00_0000_1010_1000_0000,
@@ -6,9 +6,48 @@ use std::{
 };
 
 use super::Cell;
+use crate::Error;
+
+impl Cell {
+    /// Every cell, ordered by its cell number from 1 to 9.
+    pub const ALL: [Self; 9] = [
+        Self::BottomLeft,
+        Self::BottomCentre,
+        Self::BottomRight,
+        Self::CentreLeft,
+        Self::Centre,
+        Self::CentreRight,
+        Self::TopLeft,
+        Self::TopCentre,
+        Self::TopRight,
+    ];
+
+    /// Gets the number (1-9) used to select this cell.
+    ///
+    /// Cells are numbered left-to-right, bottom-to-top.
+    pub const fn number(self) -> u8 {
+        match self {
+            Self::BottomLeft => 1,
+            Self::BottomCentre => 2,
+            Self::BottomRight => 3,
+            Self::CentreLeft => 4,
+            Self::Centre => 5,
+            Self::CentreRight => 6,
+            Self::TopLeft => 7,
+            Self::TopCentre => 8,
+            Self::TopRight => 9,
+        }
+    }
+}
+
+impl std::fmt::Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
 
 impl TryFrom<&str> for Cell {
-    type Error = String;
+    type Error = Error;
 
     /// Attempts to convert the input to a [Cell]. Returns [None] if the input is not a valid digit.
     fn try_from(x: &str) -> Result<Self, Self::Error> {
@@ -22,7 +61,7 @@ impl TryFrom<&str> for Cell {
             "7" => Ok(Self::TopLeft),
             "8" => Ok(Self::TopCentre),
             "9" => Ok(Self::TopRight),
-            _ => Err(format!("{} is not a valid cell number", x)),
+            _ => Err(Error::InvalidCell(x.to_owned())),
         }
     }
 }
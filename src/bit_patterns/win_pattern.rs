@@ -5,6 +5,39 @@ use std::ops::{BitAnd, BitOr, BitXor, Shr};
 
 use super::{Cell, WinPattern};
 
+impl WinPattern {
+    /// Every possible win pattern.
+    pub const ALL: [Self; 8] = [
+        Self::TopRow,
+        Self::CentreRow,
+        Self::BottomRow,
+        Self::LeftColumn,
+        Self::CentreColumn,
+        Self::RightColumn,
+        Self::LeftDiagonal,
+        Self::RightDiagonal,
+    ];
+
+    /// Gets the three [Cell]s that make up this pattern.
+    pub fn cells(self) -> [Cell; 3] {
+        self.into()
+    }
+
+    /// Describes the pattern in words, such as "top row".
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::TopRow => "top row",
+            Self::CentreRow => "centre row",
+            Self::BottomRow => "bottom row",
+            Self::LeftColumn => "left column",
+            Self::CentreColumn => "centre column",
+            Self::RightColumn => "right column",
+            Self::LeftDiagonal => "top-left to bottom-right diagonal",
+            Self::RightDiagonal => "top-right to bottom-left diagonal",
+        }
+    }
+}
+
 impl From<WinPattern> for u32 {
     fn from(x: WinPattern) -> Self {
         x as Self
@@ -1,9 +1,7 @@
 //! Provides functionality for the [Board] struct
 
-use std::convert::TryFrom;
-
 use crate::bit_patterns::{Cell, WinPattern};
-use crate::{Board, Player};
+use crate::{Board, Error, Outcome, Player};
 
 mod board_display;
 
@@ -23,36 +21,97 @@ impl Board {
     fn is_cell_set(&self, cell: Cell) -> bool {
         self.cells & cell != 0
     }
+}
 
-    /// Sets the cell for a given player.
-    fn set_cell(&mut self, player: Player, cell: Cell) {
-        self.cells |= cell;
-        if let Player::X = player {
-            self.cells |= cell >> 1;
+// Public block
+impl Board {
+    /// Creates an empty [Board].
+    pub const fn new() -> Self {
+        Self { cells: 0 }
+    }
+
+    /// Gets the player who set the given cell, if any.
+    pub fn owner(&self, cell: Cell) -> Option<Player> {
+        if !self.is_cell_set(cell) {
+            None
+        } else if self.has_player_set_cell(Player::X, cell) {
+            Some(Player::X)
+        } else {
+            Some(Player::O)
         }
     }
 
-    /// Handles player input for cell selection.
-    fn on_user_input(&mut self) -> Cell {
-        loop {
-            println!("Enter a value between 1-9");
-            let mut player_input_stream = String::new();
-            std::io::stdin()
-                .read_line(&mut player_input_stream)
-                .expect("read user input");
-            match Cell::try_from(player_input_stream.trim()) {
-                Ok(c) if !self.is_cell_set(c) => return c,
-                Ok(_) => println!("Sorry, this cell has already been set!"),
-                Err(e) => println!("Sorry, {}.", e),
-            }
+    /// Lists every cell that can still be played, in cell number order.
+    ///
+    /// Once the game is over there are no legal moves.
+    pub fn legal_moves(&self) -> Vec<Cell> {
+        if self.outcome() != Outcome::InProgress {
+            return Vec::new();
+        }
+        Cell::ALL
+            .iter()
+            .copied()
+            .filter(|c| !self.is_cell_set(*c))
+            .collect()
+    }
+
+    /// Sets the cell for a given player, if the move is legal.
+    pub fn apply_move(&mut self, player: Player, cell: Cell) -> Result<(), Error> {
+        if self.outcome() != Outcome::InProgress {
+            return Err(Error::GameOver);
+        }
+        if self.is_cell_set(cell) {
+            return Err(Error::CellOccupied(cell.number() as usize));
+        }
+        self.set_cell(player, cell);
+        Ok(())
+    }
+
+    /// Gets the player who has completed a [`WinPattern`], if any.
+    pub fn winner(&self) -> Option<Player> {
+        [Player::O, Player::X].iter().copied().find(|player| {
+            WinPattern::ALL
+                .iter()
+                .any(|p| self.check_player_has_won(*player, *p))
+        })
+    }
+
+    /// Lists every [`WinPattern`] that has been completed, and who completed it.
+    pub fn winning_patterns(&self) -> Vec<(Player, WinPattern)> {
+        WinPattern::ALL
+            .iter()
+            .filter_map(|pattern| {
+                [Player::O, Player::X]
+                    .iter()
+                    .find(|player| self.check_player_has_won(**player, *pattern))
+                    .map(|player| (*player, *pattern))
+            })
+            .collect()
+    }
+
+    /// Checks if either player has won the game.
+    pub fn has_player_won(&self) -> bool {
+        self.winner().is_some()
+    }
+
+    /// Gets the [Outcome] of the game as it currently stands.
+    pub fn outcome(&self) -> Outcome {
+        match self.winner() {
+            Some(player) => Outcome::Win(player),
+            None if self.is_every_cell_set() => Outcome::Draw,
+            None => Outcome::InProgress,
         }
     }
 }
 
 // Public-Crate block
 impl Board {
-    pub(crate) const fn new() -> Self {
-        Self { cells: 0 }
+    /// Sets the cell for a given player.
+    pub(crate) fn set_cell(&mut self, player: Player, cell: Cell) {
+        self.cells |= cell;
+        if let Player::X = player {
+            self.cells |= cell >> 1;
+        }
     }
 
     /// Checks if every cell has been set.
@@ -60,15 +119,16 @@ impl Board {
         !self.cells & 0b10_1010_1010_1010_1010 == 0
     }
 
-    /// Lets the active player to select a cell.
-    pub(crate) fn select_cell(&mut self, player: Player) {
-        if self.is_every_cell_set() {
-            return;
-        }
-        println!("{}: Select a Cell", player);
-        let cell = self.on_user_input();
-
-        self.set_cell(player, cell);
+    /// Lists the cells of every completed [`WinPattern`].
+    pub(crate) fn winning_line(&self) -> Vec<Cell> {
+        let mut cells: Vec<Cell> = self
+            .winning_patterns()
+            .into_iter()
+            .flat_map(|(_, pattern)| pattern.cells())
+            .collect();
+        cells.sort_unstable_by_key(|c| c.number());
+        cells.dedup();
+        cells
     }
 
     /// Applies a [`WinPattern`] over the cells for a given player.
@@ -80,24 +140,6 @@ impl Board {
 
         cells.iter().all(|c| self.has_player_set_cell(player, *c))
     }
-
-    /// Checks if the active player has won the game.
-    pub(crate) fn has_player_won(&self) -> bool {
-        [
-            WinPattern::TopRow,
-            WinPattern::CentreRow,
-            WinPattern::BottomRow,
-            WinPattern::LeftColumn,
-            WinPattern::CentreColumn,
-            WinPattern::RightColumn,
-            WinPattern::LeftDiagonal,
-            WinPattern::RightDiagonal,
-        ]
-        .iter()
-        .any(|p| {
-            self.check_player_has_won(Player::O, *p) || self.check_player_has_won(Player::X, *p)
-        })
-    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,6 @@
 //! Handles how the board is displayed to the players.
+//!
+//! The alternate format, `{:#}`, draws the cells of a winning line in brackets.
 
 use std::convert::TryFrom;
 
@@ -9,9 +11,17 @@ const fn draw_top_line() -> &'static str {
     "-------------\n"
 }
 
-fn draw_cell_line(cell_triple: [char; 3]) -> String {
+fn draw_cell(mark: char, highlighted: bool) -> String {
+    if highlighted {
+        format!("[{}]", mark)
+    } else {
+        format!(" {} ", mark)
+    }
+}
+
+fn draw_cell_line(cell_triple: [String; 3]) -> String {
     format!(
-        "| {} | {} | {} |\n",
+        "|{}|{}|{}|\n",
         cell_triple[2], cell_triple[1], cell_triple[0]
     )
 }
@@ -19,22 +29,30 @@ fn draw_cell_line(cell_triple: [char; 3]) -> String {
 impl std::fmt::Display for Board {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let mut output_string = String::new();
+        let winning_line = if f.alternate() {
+            self.winning_line()
+        } else {
+            Vec::new()
+        };
 
         for j in (0..7).rev() {
             if j % 2 == 0 {
                 output_string += draw_top_line();
             } else {
-                let mut cell_triple: [char; 3] = ['E'; 3];
+                let mut cell_triple: [String; 3] = Default::default();
                 for (i, c) in ((3 * (j / 2))..3 * (j / 2 + 1))
                     .map(|i| (i + 1).to_string())
                     .filter_map(|i| Cell::try_from(i.as_str()).ok())
                     .enumerate()
                 {
-                    if self.has_player_set_cell(Player::X, c) {
-                        cell_triple[2 - (i % 3)] = 'X';
+                    let mark = if self.has_player_set_cell(Player::X, c) {
+                        'X'
                     } else if self.has_player_set_cell(Player::O, c) {
-                        cell_triple[2 - (i % 3)] = 'O';
+                        'O'
+                    } else {
+                        'E'
                     };
+                    cell_triple[2 - (i % 3)] = draw_cell(mark, winning_line.contains(&c));
                 }
                 output_string += &draw_cell_line(cell_triple);
             }
@@ -507,6 +507,14 @@ mod board_display_tests {
         assert_eq!("-------------\n| O | O | O |\n-------------\n| O | X | X |\n-------------\n| X | X | X |\n-------------\n", &board.to_string());
     }
 
+    #[test]
+    fn alternate_display_brackets_the_winning_line() {
+        let (board, _) = Board::from_notation("XO./OX./..X o").unwrap();
+        assert_eq!("-------------\n|[X]| O | E |\n-------------\n| O |[X]| E |\n-------------\n| E | E |[X]|\n-------------\n", format!("{:#}", board));
+        let (board, _) = Board::from_notation("XO./OX./... x").unwrap();
+        assert_eq!(board.to_string(), format!("{:#}", board));
+    }
+
     #[test]
     fn board_displays_cells_correctly_3() {
         let mut board = Board::new();
@@ -524,3 +532,210 @@ mod board_display_tests {
         assert_eq!("-------------\n| O | X | O |\n-------------\n| X | O | X |\n-------------\n| O | X | O |\n-------------\n", &board.to_string());
     }
 }
+
+#[cfg(test)]
+mod api_tests {
+    use crate::bit_patterns::{Cell, WinPattern};
+    use crate::{Board, Error, Outcome, Player};
+
+    #[test]
+    fn owner_reports_who_set_each_cell() {
+        let mut board = Board::new();
+        board.apply_move(Player::X, Cell::Centre).unwrap();
+        board.apply_move(Player::O, Cell::TopRight).unwrap();
+        assert_eq!(Some(Player::X), board.owner(Cell::Centre));
+        assert_eq!(Some(Player::O), board.owner(Cell::TopRight));
+        assert_eq!(None, board.owner(Cell::BottomLeft));
+    }
+
+    #[test]
+    fn legal_moves_skip_set_cells() {
+        let mut board = Board::new();
+        assert_eq!(Cell::ALL.to_vec(), board.legal_moves());
+        board.apply_move(Player::X, Cell::BottomLeft).unwrap();
+        board.apply_move(Player::O, Cell::TopRight).unwrap();
+        assert_eq!(&Cell::ALL[1..8], board.legal_moves().as_slice());
+    }
+
+    #[test]
+    fn apply_move_rejects_set_cell() {
+        let mut board = Board::new();
+        board.apply_move(Player::X, Cell::Centre).unwrap();
+        assert!(matches!(
+            board.apply_move(Player::O, Cell::Centre),
+            Err(Error::CellOccupied(5))
+        ));
+        assert_eq!(Some(Player::X), board.owner(Cell::Centre));
+    }
+
+    #[test]
+    fn apply_move_rejects_moves_after_a_win() {
+        let mut board = Board::new();
+        for cell in &[Cell::TopLeft, Cell::TopCentre, Cell::TopRight] {
+            board.apply_move(Player::O, *cell).unwrap();
+        }
+        assert_eq!(Outcome::Win(Player::O), board.outcome());
+        assert!(board.legal_moves().is_empty());
+        assert!(matches!(
+            board.apply_move(Player::X, Cell::Centre),
+            Err(Error::GameOver)
+        ));
+    }
+
+    #[test]
+    fn outcome_reports_draw() {
+        let mut board = Board::new();
+        let moves = [
+            (Player::X, Cell::Centre),
+            (Player::O, Cell::TopLeft),
+            (Player::X, Cell::TopCentre),
+            (Player::O, Cell::BottomCentre),
+            (Player::X, Cell::CentreLeft),
+            (Player::O, Cell::CentreRight),
+            (Player::X, Cell::BottomLeft),
+            (Player::O, Cell::TopRight),
+            (Player::X, Cell::BottomRight),
+        ];
+        for (player, cell) in &moves {
+            assert_eq!(Outcome::InProgress, board.outcome());
+            board.apply_move(*player, *cell).unwrap();
+        }
+        assert_eq!(None, board.winner());
+        assert_eq!(Outcome::Draw, board.outcome());
+    }
+
+    #[test]
+    fn winning_patterns_report_who_completed_them() {
+        let (board, _) = Board::from_notation("XXX/OXO/OOX o").unwrap();
+        assert_eq!(
+            vec![
+                (Player::X, WinPattern::TopRow),
+                (Player::X, WinPattern::LeftDiagonal)
+            ],
+            board.winning_patterns()
+        );
+        let (board, _) = Board::from_notation("XX./OOO/X.. x").unwrap();
+        assert_eq!(
+            vec![(Player::O, WinPattern::CentreRow)],
+            board.winning_patterns()
+        );
+        assert_eq!("centre row", WinPattern::CentreRow.name());
+        assert!(Board::new().winning_patterns().is_empty());
+    }
+
+    #[test]
+    fn winning_line_lists_completed_patterns() {
+        let (board, _) = Board::from_notation("XXX/OO./... o").unwrap();
+        assert_eq!(
+            vec![Cell::TopLeft, Cell::TopCentre, Cell::TopRight],
+            board.winning_line()
+        );
+        let (board, _) = Board::from_notation("XO./OX./..X o").unwrap();
+        assert_eq!(
+            vec![Cell::BottomRight, Cell::Centre, Cell::TopLeft],
+            board.winning_line()
+        );
+        assert!(Board::new().winning_line().is_empty());
+    }
+
+    #[test]
+    fn cell_numbers_match_parsing() {
+        use std::convert::TryFrom;
+
+        for cell in &Cell::ALL {
+            assert_eq!(*cell, Cell::try_from(cell.to_string().as_str()).unwrap());
+        }
+    }
+}
+
+#[cfg(test)]
+mod notation_tests {
+    use crate::bit_patterns::Cell;
+    use crate::{Board, Player};
+
+    #[test]
+    fn empty_board_round_trips() {
+        assert_eq!(".../.../... x", Board::new().to_notation(Player::X));
+        assert_eq!(
+            Ok((Board::new(), Player::O)),
+            Board::from_notation(".../.../... o")
+        );
+    }
+
+    #[test]
+    fn notation_lists_rows_top_to_bottom() {
+        let (board, to_move) = Board::from_notation("XO./.X./..O x").unwrap();
+        assert_eq!(Player::X, to_move);
+        assert_eq!(Some(Player::X), board.owner(Cell::TopLeft));
+        assert_eq!(Some(Player::O), board.owner(Cell::TopCentre));
+        assert_eq!(Some(Player::X), board.owner(Cell::Centre));
+        assert_eq!(Some(Player::O), board.owner(Cell::BottomRight));
+        assert_eq!(
+            4,
+            Cell::ALL
+                .iter()
+                .filter(|c| board.owner(**c).is_some())
+                .count()
+        );
+        assert_eq!(0b11_1000_0011_0000_0010, board.cells);
+        assert_eq!("XO./.X./..O x", board.to_notation(to_move));
+    }
+
+    #[test]
+    fn notation_matches_set_cells() {
+        let mut board = Board::new();
+        board.set_cell(Player::O, Cell::TopLeft);
+        board.set_cell(Player::X, Cell::CentreRight);
+        board.set_cell(Player::O, Cell::BottomLeft);
+        assert_eq!("O../..X/O.. x", board.to_notation(Player::X));
+        assert_eq!(
+            Ok((board, Player::X)),
+            Board::from_notation("O../..X/O.. x")
+        );
+    }
+
+    #[test]
+    fn wrong_cell_character_is_reported() {
+        let error = Board::from_notation("XO./.Z./..O x").unwrap_err();
+        assert_eq!(5, error.position());
+        assert_eq!(Some('Z'), error.found());
+        assert_eq!(
+            "expected X, O or . at character 6 but found 'Z'",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn wrong_separator_is_reported() {
+        let error = Board::from_notation("XO.-.X./..O x").unwrap_err();
+        assert_eq!(3, error.position());
+        assert_eq!("/", error.expected());
+
+        let error = Board::from_notation("XO./.X./..Ox").unwrap_err();
+        assert_eq!(11, error.position());
+        assert_eq!("a space", error.expected());
+    }
+
+    #[test]
+    fn wrong_side_to_move_is_reported() {
+        let error = Board::from_notation("XO./.X./..O X").unwrap_err();
+        assert_eq!(12, error.position());
+        assert_eq!(Some('X'), error.found());
+        assert_eq!("x or o", error.expected());
+    }
+
+    #[test]
+    fn short_and_long_notations_are_reported() {
+        let error = Board::from_notation("XO./.X").unwrap_err();
+        assert_eq!(6, error.position());
+        assert_eq!(None, error.found());
+        assert_eq!(
+            "expected X, O or . at character 7 but the notation ended",
+            error.to_string()
+        );
+
+        let error = Board::from_notation("XO./.X./..O x!").unwrap_err();
+        assert_eq!(13, error.position());
+        assert_eq!("the end of the notation", error.expected());
+    }
+}
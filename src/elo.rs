@@ -0,0 +1,91 @@
+//! Provides the Elo ratings of the [Profile] struct
+//!
+//! Every profile starts at [INITIAL_RATING]. After a game, each player gains
+//! or loses points in proportion to how far the result beat or fell short of
+//! the score their rating difference predicted, so beating a stronger player
+//! is worth more than beating a weaker one.
+
+use crate::{GameResult, Profile, Profiles};
+
+/// The rating of a profile that has not played a rated game.
+pub(crate) const INITIAL_RATING: i32 = 1500;
+
+/// The most points a single game can move a rating.
+const K_FACTOR: f64 = 32.0;
+
+/// Scores a result the way Elo counts it: one for a win, a half for a draw and nothing for a loss.
+fn score(result: GameResult) -> f64 {
+    match result {
+        GameResult::Win => 1.0,
+        GameResult::Draw => 0.5,
+        GameResult::Loss => 0.0,
+    }
+}
+
+/// Predicts the score of a player against an opponent, from their ratings.
+fn expected_score(rating: i32, opponent: i32) -> f64 {
+    1.0 / (1.0 + 10f64.powf(f64::from(opponent - rating) / 400.0))
+}
+
+// Public block
+impl Profile {
+    /// Updates the rating after a game against an opponent of the given rating,
+    /// and returns how much it changed.
+    pub fn rate(&mut self, opponent: i32, result: GameResult) -> i32 {
+        let change =
+            (K_FACTOR * (score(result) - expected_score(self.rating, opponent))).round() as i32;
+        self.rating += change;
+        change
+    }
+}
+
+/// Every [Profile] ranked by rating, as printed by the leaderboard.
+pub(crate) struct Leaderboard<'a> {
+    profiles: Vec<&'a Profile>,
+}
+
+// Public-Crate block
+impl Profiles {
+    /// Ranks every profile from the highest rating down, breaking ties by name.
+    pub(crate) fn leaderboard(&self) -> Leaderboard<'_> {
+        let mut profiles: Vec<&Profile> = self.profiles().iter().collect();
+        profiles.sort_by(|a, b| b.rating.cmp(&a.rating).then_with(|| a.name.cmp(&b.name)));
+        Leaderboard { profiles }
+    }
+}
+
+impl std::fmt::Display for Leaderboard<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let width = self
+            .profiles
+            .iter()
+            .map(|profile| profile.name.len())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+        writeln!(
+            f,
+            "Rank | {:<width$} | Rating | Games | Won | Drawn | Lost",
+            "Name",
+            width = width
+        )?;
+        for (rank, profile) in self.profiles.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>4} | {:<width$} | {:>6} | {:>5} | {:>3} | {:>5} | {:>4}",
+                rank + 1,
+                profile.name,
+                profile.rating,
+                profile.games(),
+                profile.wins,
+                profile.draws,
+                profile.losses,
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod elo_tests {
+    use crate::{GameResult, Profile, Profiles};
+
+    #[test]
+    fn even_players_swap_half_the_k_factor() {
+        let mut alice = Profile::new("Alice");
+        let mut bob = Profile::new("Bob");
+        assert_eq!(1500, alice.rating);
+        assert_eq!(16, alice.rate(bob.rating, GameResult::Win));
+        assert_eq!(-16, bob.rate(1500, GameResult::Loss));
+        assert_eq!((1516, 1484), (alice.rating, bob.rating));
+    }
+
+    #[test]
+    fn upsets_are_worth_more() {
+        let mut underdog = Profile::new("Underdog");
+        assert_eq!(29, underdog.rate(1900, GameResult::Win));
+        let mut favourite = Profile::new("Favourite");
+        favourite.rating = 1900;
+        assert_eq!(3, favourite.rate(1500, GameResult::Win));
+    }
+
+    #[test]
+    fn draws_move_ratings_towards_each_other() {
+        let mut weaker = Profile::new("Weaker");
+        assert_eq!(4, weaker.rate(1600, GameResult::Draw));
+        let mut even = Profile::new("Even");
+        assert_eq!(0, even.rate(1500, GameResult::Draw));
+    }
+
+    #[test]
+    fn leaderboard_ranks_by_rating_then_name() {
+        let mut profiles = Profiles::default();
+        profiles.profile_mut("Cy").rating = 1450;
+        profiles.profile_mut("Computer (Perfect)").rating = 1620;
+        profiles.profile_mut("Bob");
+        profiles
+            .profile_mut("Alice")
+            .record(GameResult::Win, 5, None);
+        assert_eq!(
+            "Rank | Name               | Rating | Games | Won | Drawn | Lost\n\
+             \x20  1 | Computer (Perfect) |   1620 |     0 |   0 |     0 |    0\n\
+             \x20  2 | Alice              |   1500 |     1 |   1 |     0 |    0\n\
+             \x20  3 | Bob                |   1500 |     0 |   0 |     0 |    0\n\
+             \x20  4 | Cy                 |   1450 |     0 |   0 |     0 |    0\n",
+            profiles.leaderboard().to_string()
+        );
+    }
+}
@@ -0,0 +1,196 @@
+//! Provides the computer player for the [Board] struct.
+//!
+//! The engine runs a negamax search with alpha-beta pruning directly over
+//! the bit patterns of the [Board]. Scores are given from the point of view
+//! of the player to move: quicker wins score higher and slower losses score
+//! lower, so the engine never stalls a won game nor gives up a lost one early.
+//!
+//! A full search on the classic board is answered from a table of every
+//! position, solved at compile time, so perfect play costs a single lookup.
+//! Misère, where completing a line loses, is searched the same way with the
+//! scores of finished games turned around.
+
+use crate::bit_patterns::Cell;
+use crate::{Board, Difficulty, Outcome, Player, Rating, Rng};
+
+/// The score of a win on the very first move, before depth is subtracted.
+const WIN_SCORE: i32 = 10;
+
+/// How many moves ahead the [`Difficulty::Hard`] engine looks.
+const HARD_SEARCH_DEPTH: i32 = 3;
+
+/// Enough depth to search every game to the end.
+pub(crate) const FULL_SEARCH_DEPTH: i32 = 9;
+
+mod table;
+
+/// Turns a score into the number of moves until the game is won or lost.
+const fn rating(score: i32) -> Rating {
+    if score > 0 {
+        Rating::Win((WIN_SCORE - score) as usize)
+    } else if score < 0 {
+        Rating::Loss((WIN_SCORE + score) as usize)
+    } else {
+        Rating::Draw
+    }
+}
+
+// Private block
+impl Board {
+    /// Scores the board for the player about to move.
+    ///
+    /// Positions deeper than `limit` moves are scored as a draw.
+    /// In Misère, completing a line is scored as a loss.
+    fn negamax(
+        &self,
+        player: Player,
+        misere: bool,
+        depth: i32,
+        limit: i32,
+        mut alpha: i32,
+        beta: i32,
+    ) -> i32 {
+        match self.outcome() {
+            Outcome::Win(winner) if (winner == player) != misere => WIN_SCORE - depth,
+            Outcome::Win(_) => depth - WIN_SCORE,
+            Outcome::Draw => 0,
+            Outcome::InProgress if depth >= limit => 0,
+            Outcome::InProgress => {
+                let mut best = -WIN_SCORE;
+                for cell in self.legal_moves() {
+                    let mut next = *self;
+                    next.set_cell(player, cell);
+                    let score =
+                        -next.negamax(player.opponent(), misere, depth + 1, limit, -beta, -alpha);
+                    best = best.max(score);
+                    alpha = alpha.max(score);
+                    if alpha >= beta {
+                        break;
+                    }
+                }
+                best
+            }
+        }
+    }
+
+    /// Scores every legal move for the given player, looking `limit` moves ahead.
+    fn score_moves(&self, player: Player, misere: bool, limit: i32) -> Vec<(Cell, i32)> {
+        self.legal_moves()
+            .into_iter()
+            .map(|cell| {
+                let mut next = *self;
+                next.set_cell(player, cell);
+                let score =
+                    -next.negamax(player.opponent(), misere, 1, limit, -WIN_SCORE, WIN_SCORE);
+                (cell, score)
+            })
+            .collect()
+    }
+
+    /// Picks a random move among those with the best score.
+    fn random_best_move(
+        &self,
+        player: Player,
+        misere: bool,
+        limit: i32,
+        rng: &mut Rng,
+    ) -> Option<Cell> {
+        let scores = self.score_moves(player, misere, limit);
+        let best = scores.iter().map(|(_, score)| *score).max()?;
+        let best_moves: Vec<Cell> = scores
+            .into_iter()
+            .filter(|(_, score)| *score == best)
+            .map(|(cell, _)| cell)
+            .collect();
+        rng.choose(&best_moves).copied()
+    }
+}
+
+// Public block
+impl Board {
+    /// Scores a move for the given player under perfect play from both sides.
+    ///
+    /// Positive scores are wins, negative scores are losses and zero is a draw.
+    /// Returns [None] if the move is not legal.
+    pub fn score_move(&self, player: Player, cell: Cell) -> Option<i32> {
+        let mut next = *self;
+        next.apply_move(player, cell).ok()?;
+        Some(-i32::from(table::step_back(
+            next.solution(player.opponent()).score,
+        )))
+    }
+
+    /// Rates every legal move for the given player under perfect play from both sides.
+    pub fn rate_moves(&self, player: Player) -> Vec<(Cell, Rating)> {
+        self.legal_moves()
+            .into_iter()
+            .filter_map(|cell| Some((cell, rating(self.score_move(player, cell)?))))
+            .collect()
+    }
+
+    /// Finds the best move for the given player, assuming perfect play from both sides.
+    ///
+    /// Ties are broken by cell number. Returns [None] if the game is already over.
+    pub fn best_move(&self, player: Player) -> Option<Cell> {
+        self.solution(player).cells().first().copied()
+    }
+
+    /// Picks a move for the given player at the given [Difficulty].
+    ///
+    /// Returns [None] if the game is already over.
+    pub fn computer_move(
+        &self,
+        player: Player,
+        difficulty: Difficulty,
+        rng: &mut Rng,
+    ) -> Option<Cell> {
+        match difficulty {
+            Difficulty::Easy => rng.choose(&self.legal_moves()).copied(),
+            Difficulty::Medium => self
+                .winning_moves(player)
+                .first()
+                .copied()
+                .or_else(|| self.blocking_moves(player).first().copied())
+                .or_else(|| rng.choose(&self.legal_moves()).copied()),
+            Difficulty::Hard => self.random_best_move(player, false, HARD_SEARCH_DEPTH, rng),
+            Difficulty::Perfect => rng.choose(&self.solution(player).cells()).copied(),
+        }
+    }
+
+    /// Rates every legal move for the given player in Misère, where completing a line loses,
+    /// under perfect play from both sides.
+    pub fn rate_misere_moves(&self, player: Player) -> Vec<(Cell, Rating)> {
+        self.score_moves(player, true, FULL_SEARCH_DEPTH)
+            .into_iter()
+            .map(|(cell, score)| (cell, rating(score)))
+            .collect()
+    }
+
+    /// Picks a move for the given player at the given [Difficulty] in Misère, where completing a line loses.
+    ///
+    /// Returns [None] if the game is already over.
+    pub fn misere_move(
+        &self,
+        player: Player,
+        difficulty: Difficulty,
+        rng: &mut Rng,
+    ) -> Option<Cell> {
+        match difficulty {
+            Difficulty::Easy => rng.choose(&self.legal_moves()).copied(),
+            Difficulty::Medium => {
+                let losing = self.winning_moves(player);
+                let safe: Vec<Cell> = self
+                    .legal_moves()
+                    .into_iter()
+                    .filter(|cell| !losing.contains(cell))
+                    .collect();
+                rng.choose(&safe).or_else(|| losing.first()).copied()
+            }
+            Difficulty::Hard => self.random_best_move(player, true, HARD_SEARCH_DEPTH, rng),
+            Difficulty::Perfect => self.random_best_move(player, true, FULL_SEARCH_DEPTH, rng),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,209 @@
+//! Holds the perfect-play table for the [Board], built at compile time.
+//!
+//! Every arrangement of the nine cells is keyed by a base-3 number, with one
+//! digit per cell: 0 for empty, 1 for the player to move and 2 for their
+//! opponent. Keying by the player to move means one table serves both sides.
+//! The table is solved backwards from full boards, so each position only
+//! needs the solutions of the positions one move later.
+
+use super::WIN_SCORE;
+use crate::bit_patterns::{Cell, WinPattern};
+use crate::{Board, Player};
+
+/// How many ways there are to fill nine cells with nothing, X or O.
+const POSITIONS: usize = 19_683;
+
+/// The value of each base-3 digit, by cell index.
+const POWERS: [usize; 9] = [1, 3, 9, 27, 81, 243, 729, 2_187, 6_561];
+
+/// Every [WinPattern], with one bit per cell index.
+const LINES: [u16; 8] = lines();
+
+/// The solution of every position.
+static TABLE: [Solution; POSITIONS] = solve();
+
+/// The value of a position under perfect play, and the moves that keep it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct Solution {
+    /// The score for the player to move, as given by a full search.
+    pub(super) score: i8,
+    /// The best moves, with one bit per cell index.
+    pub(super) best_moves: u16,
+}
+
+const fn lines() -> [u16; 8] {
+    let mut lines = [0; 8];
+    let mut pattern = 0;
+    while pattern < lines.len() {
+        let mut index = 0;
+        while index < 9 {
+            if WinPattern::ALL[pattern] as u32 & (0b10 << (index * 2)) != 0 {
+                lines[pattern] |= 1 << index;
+            }
+            index += 1;
+        }
+        pattern += 1;
+    }
+    lines
+}
+
+const fn has_line(cells: u16) -> bool {
+    let mut line = 0;
+    while line < LINES.len() {
+        if cells & LINES[line] == LINES[line] {
+            return true;
+        }
+        line += 1;
+    }
+    false
+}
+
+/// Splits a key into the cells of the player to move and of their opponent.
+const fn decode(key: usize) -> (u16, u16) {
+    let (mut own, mut opponent) = (0, 0);
+    let mut index = 0;
+    while index < 9 {
+        match key / POWERS[index] % 3 {
+            1 => own |= 1 << index,
+            2 => opponent |= 1 << index,
+            _ => (),
+        }
+        index += 1;
+    }
+    (own, opponent)
+}
+
+const fn encode(own: u16, opponent: u16) -> usize {
+    let mut key = 0;
+    let mut index = 0;
+    while index < 9 {
+        if own & (1 << index) != 0 {
+            key += POWERS[index];
+        } else if opponent & (1 << index) != 0 {
+            key += 2 * POWERS[index];
+        }
+        index += 1;
+    }
+    key
+}
+
+/// Scores a position one move earlier, so wins and losses are one move further away.
+pub(super) const fn step_back(score: i8) -> i8 {
+    if score > 0 {
+        score - 1
+    } else if score < 0 {
+        score + 1
+    } else {
+        0
+    }
+}
+
+/// Solves a position from the solutions of the positions one move later.
+///
+/// `swapped` is the key of the same cells seen by the opponent.
+const fn solve_position(
+    table: &[Solution; POSITIONS],
+    swapped: usize,
+    own: u16,
+    opponent: u16,
+) -> Solution {
+    let finished = Solution {
+        score: 0,
+        best_moves: 0,
+    };
+    if has_line(opponent) {
+        return Solution {
+            score: -(WIN_SCORE as i8),
+            ..finished
+        };
+    }
+    if has_line(own) {
+        return Solution {
+            score: WIN_SCORE as i8,
+            ..finished
+        };
+    }
+    let mut best = finished;
+    let mut first = true;
+    let mut index = 0;
+    while index < 9 {
+        let cell = 1 << index;
+        if (own | opponent) & cell == 0 {
+            let reply = &table[swapped + 2 * POWERS[index]];
+            let score = -step_back(reply.score);
+            if first || score > best.score {
+                best = Solution {
+                    score,
+                    best_moves: cell,
+                };
+                first = false;
+            } else if score == best.score {
+                best.best_moves |= cell;
+            }
+        }
+        index += 1;
+    }
+    best
+}
+
+const fn solve() -> [Solution; POSITIONS] {
+    let mut table = [Solution {
+        score: 0,
+        best_moves: 0,
+    }; POSITIONS];
+    let mut cells = [(0, 0); POSITIONS];
+    let mut swapped = [0; POSITIONS];
+    let mut key = 0;
+    while key < POSITIONS {
+        let (own, opponent) = decode(key);
+        cells[key] = (own, opponent);
+        swapped[key] = encode(opponent, own);
+        key += 1;
+    }
+    // Solve fuller boards first, so every reply is solved before the move leading to it.
+    let mut set = 10;
+    while set > 0 {
+        set -= 1;
+        key = 0;
+        while key < POSITIONS {
+            let (own, opponent) = cells[key];
+            if (own | opponent).count_ones() == set {
+                table[key] = solve_position(&table, swapped[key], own, opponent);
+            }
+            key += 1;
+        }
+    }
+    table
+}
+
+// Public-Crate block
+impl Solution {
+    /// Lists the best moves, in cell number order.
+    pub(super) fn cells(self) -> Vec<Cell> {
+        Cell::ALL
+            .iter()
+            .copied()
+            .filter(|cell| self.best_moves & (1 << ((*cell as u32).trailing_zeros() / 2)) != 0)
+            .collect()
+    }
+}
+
+// Public-Crate block
+impl Board {
+    /// Looks up the solution of the board for the given player to move.
+    pub(super) fn solution(&self, player: Player) -> Solution {
+        let mut key = 0;
+        for (index, power) in POWERS.iter().enumerate() {
+            let cell = self.cells >> (index * 2);
+            if cell & 0b10 != 0 {
+                let is_x = cell & 0b01 != 0;
+                key += if is_x == (player == Player::X) {
+                    *power
+                } else {
+                    2 * power
+                };
+            }
+        }
+        TABLE[key]
+    }
+}
@@ -0,0 +1,236 @@
+#[cfg(test)]
+mod best_move_tests {
+    use crate::bit_patterns::Cell;
+    use crate::{Board, Outcome, Player};
+
+    /// Plays every possible reply against the engine and checks it never loses.
+    fn never_loses(board: Board, to_move: Player, engine: Player) {
+        match board.outcome() {
+            Outcome::Win(winner) => assert_eq!(engine, winner, "Engine lost:\n{}", board),
+            Outcome::Draw => (),
+            Outcome::InProgress if to_move == engine => {
+                let mut next = board;
+                next.set_cell(engine, board.best_move(engine).unwrap());
+                never_loses(next, to_move.opponent(), engine);
+            }
+            Outcome::InProgress => {
+                for cell in board.legal_moves() {
+                    let mut next = board;
+                    next.set_cell(to_move, cell);
+                    never_loses(next, to_move.opponent(), engine);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn engine_takes_immediate_win() {
+        let (board, _) = Board::from_notation("XX./.../OO. x").unwrap();
+        assert_eq!(Some(Cell::TopRight), board.best_move(Player::X));
+        assert_eq!(Some(Cell::BottomRight), board.best_move(Player::O));
+    }
+
+    #[test]
+    fn engine_blocks_immediate_loss() {
+        let (board, _) = Board::from_notation("XX./.O./... o").unwrap();
+        assert_eq!(Some(Cell::TopRight), board.best_move(Player::O));
+    }
+
+    #[test]
+    fn engine_has_no_move_on_finished_board() {
+        let (board, _) = Board::from_notation("OOO/.../... x").unwrap();
+        assert_eq!(None, board.best_move(Player::X));
+    }
+
+    #[test]
+    fn score_move_rates_wins_and_losses() {
+        let (board, _) = Board::from_notation("XX./.../OO. x").unwrap();
+        assert!(board.score_move(Player::X, Cell::TopRight).unwrap() > 0);
+        assert!(board.score_move(Player::X, Cell::Centre).unwrap() < 0);
+        assert_eq!(None, board.score_move(Player::X, Cell::TopLeft));
+    }
+
+    #[test]
+    fn engine_against_itself_is_a_draw() {
+        let mut board = Board::new();
+        let mut player = Player::X;
+        while let Some(cell) = board.best_move(player) {
+            board.set_cell(player, cell);
+            player.swap();
+        }
+        assert_eq!(Outcome::Draw, board.outcome());
+    }
+
+    #[test]
+    fn engine_never_loses_going_first() {
+        never_loses(Board::new(), Player::X, Player::X);
+    }
+
+    #[test]
+    fn engine_never_loses_going_second() {
+        never_loses(Board::new(), Player::X, Player::O);
+    }
+}
+
+#[cfg(test)]
+mod difficulty_tests {
+    use crate::bit_patterns::Cell;
+    use crate::{Board, Difficulty, Outcome, Player, Rng};
+
+    const DIFFICULTIES: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Medium,
+        Difficulty::Hard,
+        Difficulty::Perfect,
+    ];
+
+    #[test]
+    fn every_difficulty_plays_legal_moves_to_the_end() {
+        let mut rng = Rng::new(7);
+        for difficulty in &DIFFICULTIES {
+            let mut board = Board::new();
+            let mut player = Player::O;
+            while let Some(cell) = board.computer_move(player, *difficulty, &mut rng) {
+                assert!(board.apply_move(player, cell).is_ok());
+                player.swap();
+            }
+            assert_ne!(Outcome::InProgress, board.outcome());
+        }
+    }
+
+    #[test]
+    fn medium_and_above_take_immediate_wins() {
+        let (board, _) = Board::from_notation("XX./.../OO. x").unwrap();
+        let mut rng = Rng::new(1);
+        for difficulty in &DIFFICULTIES[1..] {
+            for _ in 0..10 {
+                assert_eq!(
+                    Some(Cell::TopRight),
+                    board.computer_move(Player::X, *difficulty, &mut rng)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn medium_and_above_block_immediate_losses() {
+        let (board, _) = Board::from_notation("XX./.O./... o").unwrap();
+        let mut rng = Rng::new(2);
+        for difficulty in &DIFFICULTIES[1..] {
+            for _ in 0..10 {
+                assert_eq!(
+                    Some(Cell::TopRight),
+                    board.computer_move(Player::O, *difficulty, &mut rng)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn perfect_never_loses_to_easy() {
+        let mut rng = Rng::new(3);
+        for game in 0..50 {
+            let mut board = Board::new();
+            let mut player = if game % 2 == 0 { Player::O } else { Player::X };
+            loop {
+                let difficulty = if player == Player::X {
+                    Difficulty::Perfect
+                } else {
+                    Difficulty::Easy
+                };
+                match board.computer_move(player, difficulty, &mut rng) {
+                    Some(cell) => board.set_cell(player, cell),
+                    None => break,
+                }
+                player.swap();
+            }
+            assert_ne!(Outcome::Win(Player::O), board.outcome(), "\n{}", board);
+        }
+    }
+}
+
+#[cfg(test)]
+mod table_tests {
+    use crate::engine::FULL_SEARCH_DEPTH;
+    use crate::{Board, Outcome, Player};
+
+    #[test]
+    fn table_matches_a_full_search() {
+        for board in Board::reachable_positions() {
+            if board.outcome() != Outcome::InProgress {
+                continue;
+            }
+            for player in [Player::X, Player::O] {
+                let scores = board.score_moves(player, false, FULL_SEARCH_DEPTH);
+                let best = scores.iter().map(|(_, score)| *score).max().unwrap();
+                let best_moves: Vec<_> = scores
+                    .iter()
+                    .filter(|(_, score)| *score == best)
+                    .map(|(cell, _)| *cell)
+                    .collect();
+                let solution = board.solution(player);
+                assert_eq!(best, i32::from(solution.score), "\n{}", board);
+                assert_eq!(best_moves, solution.cells(), "\n{}", board);
+                for (cell, score) in scores {
+                    assert_eq!(Some(score), board.score_move(player, cell));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn finished_boards_have_no_best_moves() {
+        for board in Board::reachable_positions() {
+            if board.outcome() != Outcome::InProgress {
+                assert!(board.solution(Player::X).cells().is_empty());
+                assert!(board.solution(Player::O).cells().is_empty());
+                assert_eq!(None, board.best_move(Player::X));
+            }
+        }
+    }
+
+    #[test]
+    fn every_opening_is_a_draw() {
+        let solution = Board::new().solution(Player::X);
+        assert_eq!(0, solution.score);
+        assert_eq!(9, solution.cells().len());
+    }
+}
+
+#[cfg(test)]
+mod misere_tests {
+    use crate::bit_patterns::Cell;
+    use crate::{Board, Difficulty, Outcome, Player, Rating, Rng};
+
+    #[test]
+    fn completing_a_line_is_rated_as_a_loss() {
+        let (board, to_move) = Board::from_notation("XX./OO./... x").unwrap();
+        let ratings = board.rate_misere_moves(to_move);
+        assert!(ratings.contains(&(Cell::TopRight, Rating::Loss(1))));
+        assert!(ratings.iter().all(|(_, rating)| *rating != Rating::Win(1)));
+    }
+
+    #[test]
+    fn medium_and_above_avoid_completing_a_line() {
+        let (board, to_move) = Board::from_notation("XX./OO./... x").unwrap();
+        let mut rng = Rng::new(4);
+        for difficulty in [Difficulty::Medium, Difficulty::Hard, Difficulty::Perfect] {
+            for _ in 0..10 {
+                let cell = board.misere_move(to_move, difficulty, &mut rng).unwrap();
+                assert_ne!(Cell::TopRight, cell, "{:?}", difficulty);
+            }
+        }
+    }
+
+    #[test]
+    fn perfect_misere_against_itself_is_a_draw() {
+        let mut rng = Rng::new(5);
+        let mut board = Board::new();
+        let mut player = Player::X;
+        while let Some(cell) = board.misere_move(player, Difficulty::Perfect, &mut rng) {
+            board.set_cell(player, cell);
+            player.swap();
+        }
+        assert_eq!(Outcome::Draw, board.outcome(), "\n{}", board);
+    }
+}
@@ -0,0 +1,86 @@
+//! Provides functionality for the [Error] enum
+
+use std::io;
+
+use crate::{grid, Error, NotationError};
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidCell(input) => write!(f, "{} is not a valid cell number", input),
+            Self::CellOccupied(number) => write!(f, "cell {} has already been set", number),
+            Self::GameOver => write!(f, "the game is already over"),
+            Self::SubBoardDecided(board) => {
+                write!(f, "sub-board {} has already been decided", board)
+            }
+            Self::WrongSubBoard(board) => {
+                write!(f, "the next move must be in sub-board {}", board)
+            }
+            Self::InvalidSize(size) => write!(
+                f,
+                "{} is not a board size between {}-{}",
+                size,
+                grid::MIN_SIZE,
+                grid::MAX_SIZE
+            ),
+            Self::InvalidInARow { in_a_row, size } => write!(
+                f,
+                "{} in a row does not fit on a {}x{} board",
+                in_a_row, size, size
+            ),
+            Self::Notation(e) => write!(f, "{}", e),
+            Self::InvalidRecord(path, reason) => {
+                write!(f, "{} is not a valid game record: {}", path, reason)
+            }
+            Self::InvalidProfiles(path, reason) => {
+                write!(f, "{} is not a valid profiles file: {}", path, reason)
+            }
+            Self::NothingToUndo => write!(f, "there is no move to undo"),
+            Self::NothingToRedo => write!(f, "there is no move to redo"),
+            Self::Save(path, e) => write!(f, "could not save to {}: {}", path, e),
+            Self::Load(path, e) => write!(f, "could not load from {}: {}", path, e),
+            Self::Io(e) => write!(f, "{}", e),
+            Self::Eof => write!(f, "ran out of player input"),
+            Self::Protocol(message) => write!(f, "\"{}\" is not understood", message),
+            Self::Desync { expected, found } => write!(
+                f,
+                "the remote player sent move {} when move {} was due",
+                found, expected
+            ),
+            Self::IllegalRemoteMove(e) => {
+                write!(f, "the remote player made an illegal move: {}", e)
+            }
+            Self::Disconnected => write!(f, "the remote player left the game"),
+            Self::NetworkGame => write!(f, "that cannot be done in a network game"),
+            Self::NoHints => write!(f, "hints are only available on the classic board"),
+            Self::IllegalEngineMove(e) => write!(f, "the engine made an illegal move: {}", e),
+            Self::EngineExited => write!(f, "the engine stopped running"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Notation(e) => Some(e),
+            Self::Save(_, e) | Self::Load(_, e) | Self::Io(e) => Some(e),
+            Self::IllegalRemoteMove(e) | Self::IllegalEngineMove(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<NotationError> for Error {
+    fn from(e: NotationError) -> Self {
+        Self::Notation(e)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod error_tests {
+    use std::convert::TryFrom;
+    use std::io;
+
+    use crate::bit_patterns::Cell;
+    use crate::{Board, Error, Grid};
+
+    #[test]
+    fn invalid_cell_is_reported() {
+        let error = Cell::try_from("10").unwrap_err();
+        assert!(matches!(&error, Error::InvalidCell(input) if input == "10"));
+        assert_eq!("10 is not a valid cell number", error.to_string());
+    }
+
+    #[test]
+    fn grid_size_errors_name_the_limits() {
+        assert_eq!(
+            "2 is not a board size between 3-19",
+            Grid::new(2, 2).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "5 in a row does not fit on a 4x4 board",
+            Grid::new(4, 5).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn notation_errors_convert() {
+        let error: Error = Board::from_notation("XO./.Z./..O x").unwrap_err().into();
+        assert!(matches!(error, Error::Notation(_)));
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
+    #[test]
+    fn io_errors_convert() {
+        let error: Error = io::Error::new(io::ErrorKind::BrokenPipe, "closed").into();
+        assert!(matches!(error, Error::Io(_)));
+        assert_eq!("closed", error.to_string());
+    }
+}
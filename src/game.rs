@@ -1,72 +1,807 @@
 //! Provides functionality for the [Game] struct
 
-use crate::{Board, EndState, Game, Player};
-
-impl Game {
-    /// Allows the user to decide who starts the game.
-    fn select_start_player() -> Player {
-        println!("Who would like to start?");
-        println!("Press O or 1 for Player O");
-        println!("Press X or 2 for Player X");
+use std::io::{BufRead, Write};
+
+use crate::grid::MAX_SIZE;
+use crate::{
+    Controller, Difficulty, EndState, EngineProcess, Error, Game, GameRecord, GameResult, Grid,
+    Move, NextGame, Opponent, Options, Outcome, Player, Playfield, Profiles, Remote, Rng,
+    Scoreboard, StartPolicy, Ultimate,
+};
+
+// Private block
+impl<R: BufRead, W: Write> Game<R, W> {
+    /// Creates a classic game between two humans, without asking the players anything.
+    ///
+    /// Any profiles are loaded now, so a bad profiles file is reported before play.
+    fn with_io(reader: R, writer: W, options: &Options) -> Result<Self, Error> {
+        let named = options.profile_o.is_some() || options.profile_x.is_some();
+        let profiles = if named {
+            Some(Profiles::load(options.profiles_path())?)
+        } else {
+            None
+        };
+        Ok(Self {
+            board: Grid::classic().into(),
+            current_player: Player::O,
+            starter: None,
+            history: Vec::new(),
+            undone: Vec::new(),
+            player_o: Controller::Human,
+            player_x: Controller::Human,
+            rng: options.seed.map_or_else(Rng::from_time, Rng::new),
+            remote: None,
+            engine: None,
+            options: options.clone(),
+            scoreboard: Scoreboard::default(),
+            profiles,
+            reader,
+            writer,
+        })
+    }
+
+    /// Reads a trimmed line of input from the player.
+    ///
+    /// Running out of input is reported as an [`Error::Eof`] error.
+    fn read_input(&mut self) -> Result<String, Error> {
+        let mut buffer = String::new();
+        if self.reader.read_line(&mut buffer)? == 0 {
+            return Err(Error::Eof);
+        }
+        Ok(buffer.trim().to_owned())
+    }
+
+    /// Allows the user to decide what board to play on.
+    ///
+    /// Bigger boards also let the user decide how many cells in a line win.
+    fn select_playfield(&mut self) -> Result<Playfield, Error> {
+        if let Some(playfield) = self.options.playfield()? {
+            return Ok(playfield);
+        }
+        writeln!(self.writer, "Which board would you like to play on?")?;
+        writeln!(self.writer, "Press Enter or 3 for the classic 3x3 board")?;
+        writeln!(
+            self.writer,
+            "Enter a size from 4-{} for a bigger board",
+            MAX_SIZE
+        )?;
+        writeln!(self.writer, "Press U for Ultimate Tic-Tac-Toe")?;
+        writeln!(
+            self.writer,
+            "Press M for Misère Tic-Tac-Toe, where three in a row loses"
+        )?;
+        let size = loop {
+            match self.read_input()?.as_str() {
+                "" | "3" => return Ok(Grid::classic().into()),
+                "U" | "u" => return Ok(Playfield::Ultimate(Ultimate::new())),
+                "M" | "m" => return Ok(Playfield::Misere(Grid::classic())),
+                input => match input.parse::<usize>() {
+                    Ok(size) if (4..=MAX_SIZE).contains(&size) => break size,
+                    _ => writeln!(self.writer, "Sorry, please provide a valid selection.")?,
+                },
+            }
+        };
+
+        writeln!(self.writer, "How many cells in a row are needed to win?")?;
+        loop {
+            writeln!(self.writer, "Enter a value between 3-{}", size)?;
+            match self.read_input()?.parse::<usize>() {
+                Ok(in_a_row) if (3..=size).contains(&in_a_row) => {
+                    return Ok(Grid::new(size, in_a_row)?.into())
+                }
+                _ => writeln!(self.writer, "Sorry, please provide a valid selection.")?,
+            }
+        }
+    }
+
+    /// Allows the user to decide who starts the game, unless the [StartPolicy] decides.
+    ///
+    /// This must be called before the board of the last game is cleared.
+    fn select_start_player(&mut self) -> Result<Player, Error> {
+        let start = match self.next_start_player() {
+            Some(start) => start,
+            None => self.ask_start_player()?,
+        };
+        self.starter = Some(start);
+        Ok(start)
+    }
+
+    /// Asks the user who starts the game.
+    fn ask_start_player(&mut self) -> Result<Player, Error> {
+        writeln!(self.writer, "Who would like to start?")?;
+        writeln!(self.writer, "Press O or 1 for {}", self.name(Player::O))?;
+        writeln!(self.writer, "Press X or 2 for {}", self.name(Player::X))?;
+        writeln!(self.writer, "Press R or 3 for a random player")?;
+        loop {
+            match self.read_input()?.as_str() {
+                "O" | "o" | "1" => return Ok(Player::O),
+                "X" | "x" | "2" => return Ok(Player::X),
+                "R" | "r" | "3" => return Ok(self.random_player()),
+                _ => writeln!(self.writer, "Sorry, please provide a valid selection.")?,
+            }
+        }
+    }
+
+    /// Allows the user to decide which players the computer controls.
+    ///
+    /// Returns whether the computer plays O and whether it plays X.
+    fn select_computer_sides(&mut self) -> Result<(bool, bool), Error> {
+        if let Some(side) = self.options.side {
+            return Ok((side == Player::X, side == Player::O));
+        }
+        writeln!(self.writer, "Who should the computer play?")?;
+        writeln!(self.writer, "Press N or 0 for nobody")?;
+        writeln!(self.writer, "Press O or 1 for {}", self.name(Player::O))?;
+        writeln!(self.writer, "Press X or 2 for {}", self.name(Player::X))?;
+        writeln!(self.writer, "Press B or 3 for both players")?;
+        loop {
+            match self.read_input()?.as_str() {
+                "N" | "n" | "0" => return Ok((false, false)),
+                "O" | "o" | "1" => return Ok((true, false)),
+                "X" | "x" | "2" => return Ok((false, true)),
+                "B" | "b" | "3" => return Ok((true, true)),
+                _ => writeln!(self.writer, "Sorry, please provide a valid selection.")?,
+            }
+        }
+    }
+
+    /// Allows the user to decide which players the computer controls, and how well it plays.
+    fn select_computer_players(&mut self) -> Result<(Controller, Controller), Error> {
+        if let Some(Opponent::Human) = self.options.opponent {
+            return Ok((Controller::Human, Controller::Human));
+        }
+        let (plays_o, plays_x) = self.select_computer_sides()?;
+        if !plays_o && !plays_x {
+            return Ok((Controller::Human, Controller::Human));
+        }
+        let difficulty = match self.options.opponent {
+            Some(Opponent::Computer(difficulty)) => difficulty,
+            _ => self.select_difficulty()?,
+        };
+        let computer = Controller::Computer(difficulty);
+        let pick = |plays| if plays { computer } else { Controller::Human };
+        Ok((pick(plays_o), pick(plays_x)))
+    }
+
+    /// Allows the user to decide how well the computer plays.
+    fn select_difficulty(&mut self) -> Result<Difficulty, Error> {
+        writeln!(self.writer, "How well should the computer play?")?;
+        writeln!(self.writer, "Press E or 1 for Easy")?;
+        writeln!(self.writer, "Press M or 2 for Medium")?;
+        writeln!(self.writer, "Press H or 3 for Hard")?;
+        writeln!(self.writer, "Press P or 4 for Perfect")?;
+        loop {
+            match self.read_input()?.as_str() {
+                "E" | "e" | "1" => return Ok(Difficulty::Easy),
+                "M" | "m" | "2" => return Ok(Difficulty::Medium),
+                "H" | "h" | "3" => return Ok(Difficulty::Hard),
+                "P" | "p" | "4" => return Ok(Difficulty::Perfect),
+                _ => writeln!(self.writer, "Sorry, please provide a valid selection.")?,
+            }
+        }
+    }
+
+    /// Rebuilds the board by replaying every move in the history.
+    fn replay_history(&mut self) -> Result<(), Error> {
+        self.board = self.board.cleared();
+        for m in &self.history {
+            self.board.apply_move(m.player, m.cell)?;
+        }
+        Ok(())
+    }
+
+    /// Takes back moves until it is a human player's turn.
+    ///
+    /// Returns false if there was no move to take back.
+    fn undo(&mut self) -> Result<bool, Error> {
+        let mut undid_any = false;
+        while let Some(m) = self.history.pop() {
+            writeln!(
+                self.writer,
+                "Undid move {}: {} at {}",
+                m.number,
+                self.name(m.player),
+                self.board.cell_name(m.cell)
+            )?;
+            self.current_player = m.player;
+            self.undone.push(m);
+            undid_any = true;
+            if self.controller(m.player) == Controller::Human {
+                break;
+            }
+        }
+        self.replay_history()?;
+        Ok(undid_any)
+    }
+
+    /// Replays undone moves until it is a human player's turn.
+    ///
+    /// Returns false if there was no move to replay.
+    fn redo(&mut self) -> Result<bool, Error> {
+        let mut redid_any = false;
+        while let Some(m) = self.undone.pop() {
+            self.board.apply_move(m.player, m.cell)?;
+            writeln!(
+                self.writer,
+                "Redid move {}: {} at {}",
+                m.number,
+                self.name(m.player),
+                self.board.cell_name(m.cell)
+            )?;
+            self.history.push(m);
+            self.current_player = m.player.opponent();
+            redid_any = true;
+            if self.controller(self.current_player) == Controller::Human {
+                break;
+            }
+        }
+        Ok(redid_any)
+    }
+
+    /// Writes a record of the game so far to the given file.
+    fn save_game(&self, path: &str) -> Result<(), Error> {
+        let start = self
+            .history
+            .first()
+            .map_or(self.current_player, |m| m.player);
+        let moves = self.history.iter().map(|m| m.cell).collect();
+        let record = GameRecord::new(&self.board, (self.player_o, self.player_x), start, moves);
+        std::fs::write(path, record.to_string()).map_err(|e| Error::Save(path.to_owned(), e))
+    }
+
+    /// Replaces the game with the one recorded in the given file.
+    ///
+    /// A finished game cannot be resumed, so it is refused rather than scored a second time.
+    /// An engine keeps playing its side, as a record cannot start one.
+    fn load_game(&mut self, path: &str) -> Result<(), Error> {
+        let text = std::fs::read_to_string(path).map_err(|e| Error::Load(path.to_owned(), e))?;
+        let record =
+            GameRecord::parse(&text).map_err(|e| Error::InvalidRecord(path.to_owned(), e))?;
+        let board = record.replay();
+        if board.outcome() != Outcome::InProgress {
+            return Err(Error::GameOver);
+        }
+        if let Some(engine) = self.engine.as_mut() {
+            engine.new_game(&board)?;
+        }
+
+        self.board = board;
+        let (player_o, player_x) = record.controllers();
+        let keep_engine = |live, loaded| match live {
+            Controller::Engine => live,
+            _ => loaded,
+        };
+        self.player_o = keep_engine(self.player_o, player_o);
+        self.player_x = keep_engine(self.player_x, player_x);
+        self.history.clear();
+        self.undone.clear();
+        self.current_player = record.start();
+        self.starter = Some(record.start());
+        for cell in record.moves() {
+            self.history.push(Move {
+                number: self.history.len() + 1,
+                player: self.current_player,
+                cell: *cell,
+            });
+            self.current_player.swap();
+        }
+        Ok(())
+    }
+
+    /// Gets the profile a player's results are kept under: their own, if they were given one,
+    /// or one for the computer at its difficulty or the engine by its command.
+    ///
+    /// The computer and engines play each kind of board differently, so they have a profile per board,
+    /// such as `Computer (Perfect, 5x5 4)`.
+    fn profile_name(&self, player: Player) -> Option<String> {
+        let profile = match player {
+            Player::O => &self.options.profile_o,
+            Player::X => &self.options.profile_x,
+        };
+        if profile.is_some() {
+            return profile.clone();
+        }
+        match (self.controller(player), &self.options.opponent) {
+            (Controller::Computer(difficulty), _) => Some(format!(
+                "Computer ({:?}, {})",
+                difficulty,
+                self.board.name()
+            )),
+            (Controller::Engine, Some(Opponent::Engine(command))) => {
+                Some(format!("Engine ({}, {})", command, self.board.name()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Adds the finished game to the profile of each player who has one, and saves them,
+    /// returning a line for each new rating and for a failed save.
+    ///
+    /// Nothing is saved unless a player was given a profile. When both players have one,
+    /// their ratings are updated too. A failed save is reported, and play carries on.
+    fn record_profiles(&mut self, outcome: Outcome) -> Vec<String> {
+        let mut notes = Vec::new();
+        let Some(mut profiles) = self.profiles.take() else {
+            return notes;
+        };
+        let named: Vec<(Player, String)> = [Player::O, Player::X]
+            .iter()
+            .filter_map(|player| Some((*player, self.profile_name(*player)?)))
+            .collect();
+        for (player, name) in &named {
+            let opening = self
+                .history
+                .iter()
+                .find(|m| m.player == *player)
+                .map(|m| self.board.cell_name(m.cell));
+            profiles.profile_mut(name).record(
+                GameResult::of(outcome, *player),
+                self.history.len(),
+                opening,
+            );
+        }
+        if let [(_, name_o), (_, name_x)] = named.as_slice() {
+            if name_o != name_x {
+                let rating_o = profiles.profile_mut(name_o).rating;
+                let rating_x = profiles.profile_mut(name_x).rating;
+                let change_o = profiles
+                    .profile_mut(name_o)
+                    .rate(rating_x, GameResult::of(outcome, Player::O));
+                let change_x = profiles
+                    .profile_mut(name_x)
+                    .rate(rating_o, GameResult::of(outcome, Player::X));
+                for (name, rating, change) in
+                    [(name_o, rating_o, change_o), (name_x, rating_x, change_x)]
+                {
+                    notes.push(format!(
+                        "{} is now rated {} ({:+})",
+                        name,
+                        rating + change,
+                        change
+                    ));
+                }
+            }
+        }
+        if let Err(e) = profiles.save(self.options.profiles_path()) {
+            notes.push(format!("Sorry, {}.", e));
+        }
+        self.profiles = Some(profiles);
+        notes
+    }
+
+    /// Rates every move the active player could make and recommends the best one.
+    fn show_hints(&mut self) -> Result<(), Error> {
+        if !self.board.supports_hints() {
+            return Err(Error::NoHints);
+        }
+        let ratings = self.board.rate_moves(self.current_player)?;
+        let tactics = self.board.tactics(self.current_player);
+        for (cell, rating) in &ratings {
+            let notes: Vec<&str> = tactics
+                .iter()
+                .filter(|(index, _)| index == cell)
+                .map(|(_, note)| *note)
+                .collect();
+            if notes.is_empty() {
+                writeln!(self.writer, "{}: {}", self.board.cell_name(*cell), rating)?;
+            } else {
+                writeln!(
+                    self.writer,
+                    "{}: {} ({})",
+                    self.board.cell_name(*cell),
+                    rating,
+                    notes.join(", ")
+                )?;
+            }
+        }
+        // Searching backwards keeps the lowest cell among equally good moves.
+        if let Some((cell, _)) = ratings.iter().rev().max_by_key(|(_, rating)| *rating) {
+            writeln!(
+                self.writer,
+                "The best move is {}",
+                self.board.cell_name(*cell)
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Handles player input for cell selection, setting the selected cell once it is valid.
+    ///
+    /// The player may instead undo or redo a move, which hands the turn to whoever is due to move,
+    /// save the game, load another one, or ask for a hint.
+    fn on_user_input(&mut self) -> Result<(), Error> {
         loop {
-            let mut play_again_buffer = String::new();
-            std::io::stdin()
-                .read_line(&mut play_again_buffer)
-                .expect("select starting player");
-            match play_again_buffer.trim() {
-                "O" | "o" | "1" => return Player::O,
-                "X" | "x" | "2" => return Player::X,
-                _ => println!("Sorry, please provide a valid selection."),
+            writeln!(self.writer, "{}", self.board.prompt())?;
+            let input = self.read_input()?;
+            let result = match input.as_str() {
+                "U" | "u" | "undo" | "R" | "r" | "redo" if self.remote.is_some() => {
+                    Err(Error::NetworkGame)
+                }
+                command if command.starts_with("load ") && self.remote.is_some() => {
+                    Err(Error::NetworkGame)
+                }
+                "U" | "u" | "undo" => match self.undo()? {
+                    true => return Ok(()),
+                    false => Err(Error::NothingToUndo),
+                },
+                "R" | "r" | "redo" => match self.redo()? {
+                    true => return Ok(()),
+                    false => Err(Error::NothingToRedo),
+                },
+                "?" | "hint" => match self.show_hints() {
+                    Ok(()) => continue,
+                    Err(e) => Err(e),
+                },
+                command if command.starts_with("save ") => {
+                    let path = command["save ".len()..].trim();
+                    match self.save_game(path) {
+                        Ok(()) => {
+                            writeln!(self.writer, "Saved the game to {}", path)?;
+                            continue;
+                        }
+                        Err(e) => Err(e),
+                    }
+                }
+                command if command.starts_with("load ") => {
+                    let path = command["load ".len()..].trim();
+                    match self.load_game(path) {
+                        Ok(()) => {
+                            writeln!(self.writer, "Loaded the game from {}", path)?;
+                            return Ok(());
+                        }
+                        Err(e) => Err(e),
+                    }
+                }
+                _ => match self.board.parse_cell(&input) {
+                    Ok(c) if self.board.owner(c).is_some() => {
+                        writeln!(self.writer, "Sorry, this cell has already been set!")?;
+                        continue;
+                    }
+                    Ok(c) => self.make_move(c),
+                    Err(e) => Err(e),
+                },
+            };
+            match result {
+                Ok(()) => return Ok(()),
+                Err(e) => writeln!(self.writer, "Sorry, {}.", e)?,
             }
         }
     }
 }
 
-impl Game {
-    /// Creates a new [Game]. At game start a player is randomly chosen.
-    pub(crate) fn new() -> Self {
-        Self {
-            board: Board::new(),
-            current_player: Self::select_start_player(),
+// Public-Crate block
+impl<R: BufRead, W: Write> Game<R, W> {
+    /// Gets the name a player goes by, which is their profile's name if they have one.
+    pub(crate) fn name(&self, player: Player) -> String {
+        let profile = match player {
+            Player::O => &self.options.profile_o,
+            Player::X => &self.options.profile_x,
+        };
+        profile.clone().unwrap_or_else(|| player.to_string())
+    }
+
+    /// Gets who makes the moves for the given player.
+    pub(crate) const fn controller(&self, player: Player) -> Controller {
+        match player {
+            Player::O => self.player_o,
+            Player::X => self.player_x,
         }
     }
 
-    /// Resets the game.
-    pub(crate) fn reset(&mut self) {
-        self.board = Board::new();
-        self.current_player = Self::select_start_player();
+    /// Sets a cell for the active player, records the move and passes the turn on.
+    pub(crate) fn make_move(&mut self, cell: usize) -> Result<(), Error> {
+        self.board.apply_move(self.current_player, cell)?;
+        self.history.push(Move {
+            number: self.history.len() + 1,
+            player: self.current_player,
+            cell,
+        });
+        self.undone.clear();
+        self.current_player.swap();
+        Ok(())
     }
 
-    /// Handles the end state of the game.
-    pub(crate) fn on_end(&mut self) -> EndState {
-        if !self.board.has_player_won() && !self.board.is_every_cell_set() {
-            return EndState::Continue;
+    /// Sends the last move to the other instance, if this is a network game.
+    pub(crate) fn send_last_move(&mut self) -> Result<(), Error> {
+        match (self.remote.as_mut(), self.history.last()) {
+            (Some(remote), Some(m)) => remote.send_move(m.number, &self.board.cell_name(m.cell)),
+            _ => Ok(()),
         }
+    }
 
-        if self.board.has_player_won() {
-            println!("{} has won!", self.current_player);
+    /// Moves the game over to a different writer.
+    #[cfg(feature = "tui")]
+    pub(crate) fn with_writer<V: Write>(self, writer: V) -> Game<R, V> {
+        Game {
+            board: self.board,
+            current_player: self.current_player,
+            starter: self.starter,
+            history: self.history,
+            undone: self.undone,
+            player_o: self.player_o,
+            player_x: self.player_x,
+            rng: self.rng,
+            remote: self.remote,
+            engine: self.engine,
+            options: self.options,
+            scoreboard: self.scoreboard,
+            profiles: self.profiles,
+            reader: self.reader,
+            writer,
+        }
+    }
+
+    /// Creates a new [Game] that talks to the players through the given reader and writer.
+    ///
+    /// Anything not set in the [Options] is asked for.
+    pub(crate) fn new(reader: R, writer: W, options: &Options) -> Result<Self, Error> {
+        let mut game = Self::with_io(reader, writer, options)?;
+        game.board = game.select_playfield()?;
+        game.current_player = game.select_start_player()?;
+        let (player_o, player_x) = game.select_computer_players()?;
+        game.player_o = player_o;
+        game.player_x = player_x;
+        Ok(game)
+    }
+
+    /// Creates a network [Game] as its host, who plays X and chooses the board and who starts.
+    pub(crate) fn hosted(
+        reader: R,
+        writer: W,
+        options: &Options,
+        mut remote: Remote,
+    ) -> Result<Self, Error> {
+        let mut game = Self::with_io(reader, writer, options)?;
+        game.board = game.select_playfield()?;
+        game.current_player = game.select_start_player()?;
+        remote.send_setup(&game.board, game.current_player)?;
+        game.player_o = Controller::Remote;
+        game.remote = Some(remote);
+        writeln!(game.writer, "You are {}", game.name(Player::X))?;
+        Ok(game)
+    }
+
+    /// Creates a network [Game] that has joined a host, playing O on the board the host chose.
+    ///
+    /// Any board or starting player in the [Options] is ignored, since the host chooses them.
+    pub(crate) fn joined(
+        reader: R,
+        writer: W,
+        options: &Options,
+        mut remote: Remote,
+    ) -> Result<Self, Error> {
+        let mut game = Self::with_io(reader, writer, options)?;
+        writeln!(game.writer, "Waiting for the host to choose a board")?;
+        game.writer.flush()?;
+        let (board, start) = remote.receive_setup()?;
+        game.board = board;
+        game.current_player = start;
+        game.player_x = Controller::Remote;
+        game.remote = Some(remote);
+        writeln!(game.writer, "You are {}", game.name(Player::O))?;
+        Ok(game)
+    }
+
+    /// Creates a [Game] where an external engine plays in place of the computer.
+    pub(crate) fn against(
+        reader: R,
+        writer: W,
+        options: &Options,
+        mut engine: EngineProcess,
+    ) -> Result<Self, Error> {
+        let mut game = Self::with_io(reader, writer, options)?;
+        game.board = game.select_playfield()?;
+        game.current_player = game.select_start_player()?;
+        let (plays_o, plays_x) = game.select_computer_sides()?;
+        let pick = |plays| {
+            if plays {
+                Controller::Engine
+            } else {
+                Controller::Human
+            }
+        };
+        game.player_o = pick(plays_o);
+        game.player_x = pick(plays_x);
+        engine.new_game(&game.board)?;
+        game.engine = Some(engine);
+        Ok(game)
+    }
+
+    /// Adds the finished game to the scoreboard and the profiles,
+    /// returning what the players should be told about their profiles.
+    pub(crate) fn finish_game(&mut self) -> Vec<String> {
+        let outcome = self.board.outcome();
+        self.scoreboard.record(outcome);
+        self.record_profiles(outcome)
+    }
+
+    /// Decides what follows the game just finished, by the [Options] and the scoreboard.
+    ///
+    /// Network games are a single game, and a match stops once it is decided.
+    pub(crate) fn next_game(&self) -> NextGame {
+        if self.remote.is_some() || self.options.no_replay {
+            return NextGame::Stop;
+        }
+        match self.options.best_of {
+            Some(best_of) if self.scoreboard.is_decided(best_of) => NextGame::Stop,
+            Some(_) => NextGame::Match,
+            None => NextGame::Offer,
+        }
+    }
+
+    /// Announces the winner of a match once it is decided, or [None] if no match is being played.
+    pub(crate) fn match_result(&self) -> Option<String> {
+        let best_of = self.options.best_of?;
+        if !self.scoreboard.is_decided(best_of) {
+            return None;
+        }
+        Some(match self.scoreboard.leader() {
+            Some(winner) => format!("{} has won the match!", self.name(winner)),
+            None => "The match is drawn!".to_owned(),
+        })
+    }
+
+    /// Picks a player at random.
+    pub(crate) fn random_player(&mut self) -> Player {
+        if self.rng.below(2) == 0 {
+            Player::O
         } else {
-            println!("Draw!");
+            Player::X
         }
-        println!("Would you like to play again?");
-        println!("Press Y for Yes, N for No.");
+    }
+
+    /// Picks who starts the next game by the [StartPolicy], or [None] if the players should be asked.
+    pub(crate) fn next_start_player(&mut self) -> Option<Player> {
+        match (self.options.start, self.starter) {
+            (StartPolicy::Ask, _) => None,
+            (StartPolicy::Always(player), _) => Some(player),
+            (StartPolicy::Random, _) | (_, None) => Some(self.random_player()),
+            (StartPolicy::Alternate, Some(last)) => Some(last.opponent()),
+            (StartPolicy::LoserStarts, Some(last)) => match self.board.outcome() {
+                Outcome::Win(winner) => Some(winner.opponent()),
+                _ => Some(last.opponent()),
+            },
+        }
+    }
+
+    /// Resets the game.
+    pub(crate) fn reset(&mut self) -> Result<(), Error> {
+        self.current_player = self.select_start_player()?;
+        self.board = self.board.cleared();
+        self.history.clear();
+        self.undone.clear();
+        writeln!(self.writer, "{} Begins.", self.name(self.current_player))?;
+        Ok(())
+    }
+
+    /// Lets the active player to select a cell.
+    pub(crate) fn select_cell(&mut self) -> Result<(), Error> {
+        if self.board.outcome() != Outcome::InProgress {
+            return Ok(());
+        }
+        writeln!(
+            self.writer,
+            "{}: Select a Cell",
+            self.name(self.current_player)
+        )?;
+        match self.controller(self.current_player) {
+            Controller::Human => {
+                self.on_user_input()?;
+                self.send_last_move()
+            }
+            Controller::Engine => {
+                let start = self
+                    .history
+                    .first()
+                    .map_or(self.current_player, |m| m.player);
+                let moves: Vec<String> = self
+                    .history
+                    .iter()
+                    .map(|m| self.board.cell_name(m.cell))
+                    .collect();
+                let engine = self.engine.as_mut().ok_or(Error::EngineExited)?;
+                let name = engine.best_move(start, &moves)?;
+                self.board
+                    .parse_cell(&name)
+                    .and_then(|cell| self.make_move(cell))
+                    .map_err(|e| Error::IllegalEngineMove(Box::new(e)))?;
+                writeln!(self.writer, "The engine selects {}", name)?;
+                Ok(())
+            }
+            Controller::Remote => {
+                writeln!(
+                    self.writer,
+                    "Waiting for {} to move",
+                    self.name(self.current_player)
+                )?;
+                self.writer.flush()?;
+                let remote = self.remote.as_mut().ok_or(Error::Disconnected)?;
+                let (number, name) = remote.receive_move()?;
+                let expected = self.history.len() + 1;
+                if number != expected {
+                    return Err(Error::Desync {
+                        expected,
+                        found: number,
+                    });
+                }
+                self.board
+                    .parse_cell(&name)
+                    .and_then(|cell| self.make_move(cell))
+                    .map_err(|e| Error::IllegalRemoteMove(Box::new(e)))?;
+                writeln!(self.writer, "The remote player selects {}", name)?;
+                Ok(())
+            }
+            Controller::Computer(difficulty) => {
+                let cell = self
+                    .board
+                    .computer_move(self.current_player, difficulty, &mut self.rng)
+                    .ok_or(Error::GameOver)?;
+                writeln!(
+                    self.writer,
+                    "The computer selects {}",
+                    self.board.cell_name(cell)
+                )?;
+                self.make_move(cell)
+            }
+        }
+    }
+
+    /// Handles the end state of the game.
+    pub(crate) fn on_end(&mut self) -> Result<EndState, Error> {
+        let outcome = self.board.outcome();
+        match outcome {
+            Outcome::InProgress => return Ok(EndState::Continue),
+            Outcome::Win(winner) => {
+                if let Playfield::Misere(_) = self.board {
+                    writeln!(self.writer, "{} has lost!", self.name(winner.opponent()))?;
+                }
+                writeln!(self.writer, "{} has won!", self.name(winner))?;
+                for (player, line) in self.board.winning_lines() {
+                    writeln!(self.writer, "{} completed {}.", self.name(player), line)?;
+                }
+            }
+            Outcome::Draw => writeln!(self.writer, "Draw!")?,
+        }
+        for note in self.finish_game() {
+            writeln!(self.writer, "{}", note)?;
+        }
+        match self.next_game() {
+            NextGame::Offer => (),
+            NextGame::Match => {
+                writeln!(
+                    self.writer,
+                    "Game {} of a best of {} is next.",
+                    self.scoreboard.games() + 1,
+                    self.options.best_of.unwrap_or_default()
+                )?;
+                self.reset()?;
+                return Ok(EndState::Replay);
+            }
+            NextGame::Stop => {
+                if let Some(result) = self.match_result() {
+                    writeln!(self.writer, "{}", result)?;
+                }
+                return Ok(EndState::End);
+            }
+        }
+        writeln!(self.writer, "Would you like to play again?")?;
+        writeln!(self.writer, "Press Y for Yes, N for No.")?;
 
         loop {
-            let mut play_again_buffer = String::new();
-            std::io::stdin()
-                .read_line(&mut play_again_buffer)
-                .expect("user input for end of game");
-            match play_again_buffer.trim() {
-                "n" | "N" => return EndState::End,
+            match self.read_input()?.as_str() {
+                "n" | "N" => return Ok(EndState::End),
                 "y" | "Y" => {
-                    self.reset();
-                    return EndState::Replay;
+                    self.reset()?;
+                    return Ok(EndState::Replay);
                 }
                 _ => {
-                    println!("Please enter a valid argument.");
+                    writeln!(self.writer, "Please enter a valid argument.")?;
                     continue;
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests;
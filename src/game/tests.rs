@@ -0,0 +1,469 @@
+#[cfg(test)]
+mod transcript_tests {
+    use crate::{play_with, run_with, Error, GameResult, Options, Profiles};
+
+    fn run(input: &str) -> (Result<(), Error>, String) {
+        let mut output = Vec::new();
+        let result = play_with(input.as_bytes(), &mut output);
+        (result, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn scripted_game_reports_winner() {
+        let (result, transcript) = run("3\nX\nN\n7\n1\n8\n2\n9\nN\n");
+        assert!(result.is_ok());
+        assert!(transcript.starts_with("Which board would you like to play on?\n"));
+        assert!(transcript.contains("Player X Begins.\n"));
+        assert!(transcript.contains("|[X]|[X]|[X]|\n"));
+        assert!(transcript.contains("Player X has won!\nPlayer X completed the top row.\n"));
+        assert!(transcript.ends_with("Thank you for playing Tic-Tac-Toe!\n"));
+    }
+
+    #[test]
+    fn scripted_game_reports_draw() {
+        let (result, transcript) = run("3\no\n0\n5\n7\n8\n2\n4\n6\n1\n9\n3\nn\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains("Player O Begins.\n"));
+        assert!(transcript.contains("Draw!\n"));
+    }
+
+    #[test]
+    fn invalid_input_is_reported_and_retried() {
+        let (result, transcript) = run("3\n?\n1\nn\n5\n5\n10\n2\n1\n3\n9\nN\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains("Sorry, please provide a valid selection.\n"));
+        assert!(transcript.contains("Sorry, this cell has already been set!\n"));
+        assert!(transcript.contains("Sorry, 10 is not a valid cell number.\n"));
+        assert!(transcript.contains("Player O has won!\n"));
+    }
+
+    #[test]
+    fn replay_asks_for_a_new_starting_player() {
+        let (result, transcript) = run("3\nX\nN\n1\n4\n2\n5\n3\nY\nO\n1\n4\n2\n5\n3\nN\n");
+        assert!(result.is_ok());
+        assert_eq!(2, transcript.matches("Who would like to start?").count());
+        assert!(transcript.contains("Player X has won!\n"));
+        assert!(transcript.contains("Player O has won!\n"));
+    }
+
+    #[test]
+    fn running_out_of_input_ends_the_game() {
+        let (result, transcript) = run("3\nX\nN\n5\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains("Player O: Select a Cell\n"));
+        assert!(transcript.ends_with("\nThank you for playing Tic-Tac-Toe!\n"));
+    }
+
+    #[test]
+    fn running_out_of_input_during_setup_ends_the_game() {
+        let (result, transcript) = run("");
+        assert!(result.is_ok());
+        assert!(transcript.ends_with("Thank you for playing Tic-Tac-Toe!\n"));
+    }
+
+    #[test]
+    fn computer_against_itself_draws() {
+        let (result, transcript) = run("3\nX\nB\nP\nN\n");
+        assert!(result.is_ok());
+        assert_eq!(9, transcript.matches("The computer selects").count());
+        assert!(transcript.contains("Draw!\n"));
+    }
+
+    #[test]
+    fn computer_wins_against_careless_player() {
+        let (result, transcript) = run("3\nX\nO\n4\n1\n2\n4\nN\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains("Player O has won!\n"));
+    }
+
+    #[test]
+    fn scripted_game_on_bigger_board() {
+        let (result, transcript) = run("4\n2\n3\nX\nN\n1\n5\n17\n2\n6\n3\nN\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains("Enter a value between 3-4\n"));
+        assert!(transcript.contains("Sorry, please provide a valid selection.\n"));
+        assert!(transcript.contains("Enter a value between 1-16\n"));
+        assert!(transcript.contains("Sorry, 17 is not a valid cell number.\n"));
+        assert!(transcript.contains("|[X]|[X]|[X]| E |\n"));
+        assert!(transcript.contains("Player X completed the line through 1, 2 and 3.\n"));
+    }
+
+    #[test]
+    fn scripted_ultimate_game_enforces_sub_board() {
+        let (result, transcript) = run("U\nX\nN\n53\n11\n31\n4\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains("Press U for Ultimate Tic-Tac-Toe\n"));
+        assert!(transcript.contains("Enter a sub-board then a cell"));
+        assert!(transcript.contains("Enter a value between 1-9 for sub-board 3\n"));
+        assert!(transcript.contains("Sorry, the next move must be in sub-board 3.\n"));
+        assert!(transcript.contains("Enter a value between 1-9 for sub-board 1\n"));
+    }
+
+    #[test]
+    fn computer_plays_ultimate_against_itself() {
+        let (result, transcript) = run("U\nO\nB\nM\nN\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains(" has won!\n") || transcript.contains("Draw!\n"));
+    }
+
+    #[test]
+    fn undo_and_redo_keep_the_turn_consistent() {
+        let (result, transcript) = run("3\nX\nN\nu\n5\n1\nU\nU\nr\n2\nR\n1\n4\n9\nN\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains("Sorry, there is no move to undo.\n"));
+        assert!(transcript.contains("Undid move 2: Player O at 1\n"));
+        assert!(transcript.contains("Undid move 1: Player X at 5\n"));
+        assert!(transcript.contains("Redid move 1: Player X at 5\n"));
+        assert!(transcript.contains("Sorry, there is no move to redo.\n"));
+        assert!(transcript.contains("Player X has won!\n"));
+    }
+
+    #[test]
+    fn undo_against_the_computer_returns_to_the_human() {
+        let (result, transcript) = run("3\nX\nO\nP\n1\nu\nr\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains("Undid move 2: Player O at 5\n"));
+        assert!(transcript.contains("Undid move 1: Player X at 1\n"));
+        assert!(transcript.contains("Redid move 1: Player X at 1\n"));
+        assert!(transcript.contains("Redid move 2: Player O at 5\n"));
+        assert_eq!(1, transcript.matches("The computer selects").count());
+    }
+
+    #[test]
+    fn undo_works_in_ultimate() {
+        let (result, transcript) = run("U\nX\nN\n53\nu\n11\n1\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains("Undid move 1: Player X at 53\n"));
+        assert!(transcript.contains("Enter a value between 1-9 for sub-board 1\n"));
+    }
+
+    #[test]
+    fn saved_game_can_be_loaded_and_resumed() {
+        let path =
+            std::env::temp_dir().join(format!("tic_tac_toe_save_test_{}.txt", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        let (result, transcript) = run(&format!("3\nX\nN\n5\n1\nsave {}\n", path));
+        assert!(result.is_ok());
+        assert!(transcript.contains(&format!("Saved the game to {}\n", path)));
+        let saved = std::fs::read_to_string(path).unwrap();
+        assert!(saved.contains("[Start \"X\"]\n[Result \"*\"]\n"));
+        assert!(saved.ends_with("\n1. 5 2. 1\n"));
+
+        let (result, transcript) = run(&format!("3\nO\nN\nload {}\n4\n2\n6\nN\n", path));
+        std::fs::remove_file(path).unwrap();
+        assert!(result.is_ok());
+        assert!(transcript.contains(&format!("Loaded the game from {}\n", path)));
+        assert!(transcript.contains("Player X: Select a Cell\n"));
+        assert!(transcript.contains("Player X has won!\n"));
+    }
+
+    #[test]
+    fn finished_games_cannot_be_loaded() {
+        let path = std::env::temp_dir().join(format!(
+            "tic_tac_toe_finished_test_{}.txt",
+            std::process::id()
+        ));
+        let path = path.to_str().unwrap();
+        std::fs::write(
+            path,
+            "[Board \"3x3\"]\n[PlayerO \"Human\"]\n[PlayerX \"Human\"]\n[Date \"2026-10-18\"]\n[Start \"X\"]\n[Result \"X\"]\n\n1. 7 2. 1 3. 8 4. 2 5. 9\n",
+        )
+        .unwrap();
+
+        let (result, transcript) = run(&format!("3\nX\nN\nload {}\n", path));
+        std::fs::remove_file(path).unwrap();
+        assert!(result.is_ok());
+        assert!(transcript.contains("Sorry, the game is already over.\n"));
+        assert!(!transcript.contains("has won!"));
+        assert!(!transcript.contains("Games played"));
+    }
+
+    #[test]
+    fn loading_a_missing_file_is_reported() {
+        let (result, transcript) = run("3\nX\nN\nload /nonexistent/game.txt\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains("Sorry, could not load from /nonexistent/game.txt: "));
+    }
+
+    #[test]
+    fn hint_rates_every_move_and_recommends_one() {
+        let (result, transcript) = run("3\nX\nN\n7\n4\n8\n5\n?\n9\nN\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains("Enter ? or hint to see how every move would turn out.\n"));
+        assert!(transcript.contains("1: loss in 2 moves\n"));
+        assert!(transcript.contains("9: win in 1 move (wins now)\n"));
+        assert!(transcript.contains("6: draw (blocks a win)\n"));
+        assert!(transcript.contains("The best move is 9\n"));
+        assert!(transcript.contains("Player X has won!\n"));
+    }
+
+    #[test]
+    fn hints_are_refused_off_the_classic_board() {
+        let (result, transcript) = run("U\nX\nN\nhint\n");
+        assert!(result.is_ok());
+        assert!(!transcript.contains("Enter ? or hint"));
+        assert!(transcript.contains("Sorry, hints are only available on the classic board.\n"));
+    }
+
+    #[test]
+    fn misere_announces_the_loser() {
+        let (result, transcript) = run("M\nX\nN\n7\n1\n8\n2\n9\nN\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains("Press M for Misère Tic-Tac-Toe, where three in a row loses\n"));
+        assert!(transcript
+            .contains("Player X has lost!\nPlayer O has won!\nPlayer X completed the top row.\n"));
+    }
+
+    #[test]
+    fn options_skip_the_prompts() {
+        let options =
+            Options::from_args(&["--start", "X", "--opponent", "human", "--no-replay"]).unwrap();
+        let mut output = Vec::new();
+        let result = run_with(&options, "\n7\n1\n8\n2\n9\n".as_bytes(), &mut output);
+        let transcript = String::from_utf8(output).unwrap();
+        assert!(result.is_ok());
+        assert!(transcript.starts_with("Which board would you like to play on?\n"));
+        assert!(!transcript.contains("Who would like to start?"));
+        assert!(!transcript.contains("Who should the computer play?"));
+        assert!(!transcript.contains("Would you like to play again?"));
+        assert!(transcript
+            .contains("Player X has won!\nPlayer X completed the top row.\nGames played: 1\n"));
+        assert!(transcript.ends_with("Thank you for playing Tic-Tac-Toe!\n"));
+    }
+
+    #[test]
+    fn seeded_computer_games_repeat() {
+        let options = Options::from_args(&[
+            "--variant",
+            "classic",
+            "--start",
+            "O",
+            "--opponent",
+            "easy",
+            "--side",
+            "X",
+            "--seed",
+            "9",
+            "--no-replay",
+        ])
+        .unwrap();
+        let play = |input: &str| {
+            let mut output = Vec::new();
+            run_with(&options, input.as_bytes(), &mut output).unwrap();
+            String::from_utf8(output).unwrap()
+        };
+        let transcript = play("1\n2\n3\n4\n5\n6\n7\n8\n9\n");
+        assert!(transcript.starts_with("Welcome to Tic-Tac-Toe!\nPlayer O Begins.\n"));
+        assert!(transcript.contains("The computer selects"));
+        assert_eq!(transcript, play("1\n2\n3\n4\n5\n6\n7\n8\n9\n"));
+    }
+
+    fn run_options(args: &[&str], input: &str) -> String {
+        let options = Options::from_args(args).unwrap();
+        let mut output = Vec::new();
+        run_with(&options, input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn best_of_ends_once_the_match_is_won() {
+        let transcript = run_options(
+            &["--best-of", "3", "--start", "X", "--opponent", "human"],
+            "3\n1\n4\n2\n5\n3\n1\n4\n2\n5\n3\n",
+        );
+        assert!(transcript.contains("Game 2 of a best of 3 is next."));
+        assert!(!transcript.contains("Would you like to play again?"));
+        assert!(transcript.ends_with(
+            "Player X has won the match!\n\
+             Games played: 2\n\
+             Player   | Won | Drawn | Lost\n\
+             Player O |   0 |     0 |    2\n\
+             Player X |   2 |     0 |    0\n\
+             Thank you for playing Tic-Tac-Toe!\n"
+        ));
+    }
+
+    #[test]
+    fn scoreboard_is_kept_across_replays() {
+        let transcript = run_options(
+            &["--start", "X", "--opponent", "human"],
+            "3\n1\n4\n2\n5\n3\nY\n4\n1\n5\n2\n7\n3\nN\n",
+        );
+        assert!(transcript.contains(
+            "Games played: 2\n\
+             Player   | Won | Drawn | Lost\n\
+             Player O |   1 |     0 |    1\n\
+             Player X |   1 |     0 |    1\n"
+        ));
+    }
+
+    #[test]
+    fn profiles_name_the_players_and_keep_their_results() {
+        let path = std::env::temp_dir().join(format!(
+            "tic_tac_toe_profiles_test_{}.txt",
+            std::process::id()
+        ));
+        let path = path.to_str().unwrap();
+        let _ = std::fs::remove_file(path);
+        let args = [
+            "--start",
+            "X",
+            "--opponent",
+            "human",
+            "--profile-o",
+            "Alice",
+            "--profile-x",
+            "Bob",
+            "--profiles",
+            path,
+        ];
+
+        let transcript = run_options(&args, "3\n1\n4\n2\n5\n3\nN\n");
+        assert!(transcript.contains("Bob Begins."));
+        assert!(transcript.contains("Alice: Select a Cell\n"));
+        assert!(!transcript.contains("Player X"));
+        assert!(transcript.contains("Bob has won!\nBob completed the bottom row.\n"));
+        assert!(transcript.contains("Alice  |   0 |     0 |    1\nBob    |   1 |     0 |    0\n"));
+
+        assert!(transcript.contains("Alice is now rated 1484 (-16)\nBob is now rated 1516 (+16)\n"));
+
+        run_options(&args, "3\n5\n1\n9\n2\n7\n3\nN\n");
+        let profiles = Profiles::load(path).unwrap();
+        let _ = std::fs::remove_file(path);
+        let alice = &profiles.profiles()[0];
+        let bob = &profiles.profiles()[1];
+        assert_eq!(
+            ("Alice", 1, 1),
+            (alice.name.as_str(), alice.wins, alice.losses)
+        );
+        assert_eq!(("Bob", 1, 1), (bob.name.as_str(), bob.wins, bob.losses));
+        assert_eq!(Some((GameResult::Win, 1)), alice.streak);
+        assert_eq!(Some("1"), alice.favourite_opening());
+        assert_eq!(11, bob.moves);
+        assert_eq!((1501, 1499), (alice.rating, bob.rating));
+    }
+
+    #[test]
+    fn bad_profiles_file_is_reported_before_play() {
+        let path = std::env::temp_dir().join(format!(
+            "tic_tac_toe_bad_profiles_test_{}.txt",
+            std::process::id()
+        ));
+        let path = path.to_str().unwrap();
+        std::fs::write(path, "not a profile\n").unwrap();
+        let options = Options::from_args(&["--profile-x", "Alice", "--profiles", path]).unwrap();
+        let mut output = Vec::new();
+        let result = run_with(&options, "3\n".as_bytes(), &mut output);
+        std::fs::remove_file(path).unwrap();
+        assert!(matches!(result, Err(Error::InvalidProfiles(_, _))));
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn failed_profile_save_does_not_end_the_session() {
+        let transcript = run_options(
+            &[
+                "--start",
+                "X",
+                "--opponent",
+                "human",
+                "--profile-x",
+                "Alice",
+                "--profiles",
+                "/nonexistent/tic_tac_toe/profiles.txt",
+            ],
+            "3\n1\n4\n2\n5\n3\nN\n",
+        );
+        assert!(
+            transcript.contains("Sorry, could not save to /nonexistent/tic_tac_toe/profiles.txt")
+        );
+        assert!(transcript.contains("Would you like to play again?"));
+        assert!(transcript.ends_with("Thank you for playing Tic-Tac-Toe!\n"));
+    }
+
+    #[test]
+    fn computer_players_are_rated_against_profiles() {
+        let path = std::env::temp_dir().join(format!(
+            "tic_tac_toe_ratings_test_{}.txt",
+            std::process::id()
+        ));
+        let path = path.to_str().unwrap();
+        let _ = std::fs::remove_file(path);
+        let args = [
+            "--start",
+            "O",
+            "--opponent",
+            "perfect",
+            "--side",
+            "O",
+            "--profile-o",
+            "Alice",
+            "--profiles",
+            path,
+            "--no-replay",
+        ];
+        let transcript = run_options(&args, "3\n1\n2\n3\n4\n");
+        let profiles = Profiles::load(path).unwrap();
+        let _ = std::fs::remove_file(path);
+        assert!(transcript.contains("Alice is now rated 1484 (-16)\n"));
+        assert!(transcript.contains("Computer (Perfect, 3x3) is now rated 1516 (+16)\n"));
+        let names: Vec<&str> = profiles
+            .profiles()
+            .iter()
+            .map(|p| p.name.as_str())
+            .collect();
+        assert_eq!(vec!["Alice", "Computer (Perfect, 3x3)"], names);
+    }
+
+    #[test]
+    fn alternate_takes_turns_to_start() {
+        let args = ["--start", "alternate", "--opponent", "human", "--seed", "3"];
+        let transcript = run_options(&args, "3\n1\n4\n2\n5\n3\nY\n1\n4\n2\n5\n3\nN\n");
+        let first = transcript.find(" Begins.").unwrap();
+        let second = transcript.rfind(" Begins.").unwrap();
+        assert_ne!(transcript[first - 8..first], transcript[second - 8..second]);
+        assert!(!transcript.contains("Who would like to start?"));
+    }
+
+    #[test]
+    fn loser_starts_the_next_game() {
+        let transcript = run_options(
+            &["--start", "loser", "--opponent", "human", "--seed", "3"],
+            "3\n1\n4\n2\n5\n3\nY\n",
+        );
+        let loser = if transcript.contains("Player X has won!") {
+            "Player O"
+        } else {
+            "Player X"
+        };
+        assert!(transcript.contains(&format!(
+            "{} Begins.\n{}: Select a Cell\nEnter a value between 1-9\nGames played: 1\n",
+            loser, loser
+        )));
+    }
+
+    #[test]
+    fn random_start_can_be_chosen_at_the_prompt() {
+        let (result, transcript) = run("3\nR\nN\n");
+        assert!(result.is_ok());
+        assert!(transcript.contains("Press R or 3 for a random player\n"));
+        assert!(transcript.contains(" Begins.\n"));
+    }
+
+    #[test]
+    fn one_seed_repeats_the_whole_session() {
+        let args = [
+            "--start",
+            "random",
+            "--opponent",
+            "easy",
+            "--side",
+            "O",
+            "--seed",
+            "17",
+        ];
+        let input = "3\n1\n2\n3\n4\n5\n6\n7\n8\n9\nY\n1\n2\n3\n4\n5\n6\n7\n8\n9\nN\n";
+        assert_eq!(run_options(&args, input), run_options(&args, input));
+    }
+}
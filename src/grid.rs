@@ -0,0 +1,404 @@
+//! Provides functionality for the [Grid] struct
+//!
+//! A [Grid] is a square board of any size, won by setting `in_a_row` cells
+//! in a line. The classic 3×3 game keeps using the 18-bit [Board] patterns.
+//! Every other grid is stored as two multi-word bitboards:
+//! - `set`: The bits that state if a cell has been set
+//! - `crosses`: The bits that state a cell was set by [`Player::X`]
+//!
+//! Cells are indexed from 0, left-to-right, bottom-to-top, so the cell number
+//! shown to the players is always the index plus one.
+
+use std::convert::TryFrom;
+
+use crate::bit_patterns::Cell;
+use crate::{Board, Difficulty, Error, Grid, GridCells, Outcome, Player, Rng};
+
+mod grid_display;
+
+/// The number of bits in a word of a multi-word bitboard.
+const WORD_BITS: usize = 64;
+
+/// The smallest grid that can be played on.
+pub const MIN_SIZE: usize = 3;
+
+/// The largest grid that can be played on.
+pub const MAX_SIZE: usize = 19;
+
+/// Gets the word index and bit mask for a cell index.
+const fn bit(index: usize) -> (usize, u64) {
+    (index / WORD_BITS, 1 << (index % WORD_BITS))
+}
+
+/// Generates the bit pattern of every line of `in_a_row` cells on a `size` × `size` grid.
+fn win_lines(size: usize, in_a_row: usize) -> Vec<Vec<u64>> {
+    let words = (size * size).div_ceil(WORD_BITS);
+    // Right, up, up-right and up-left.
+    let directions: [(isize, isize); 4] = [(0, 1), (1, 0), (1, 1), (1, -1)];
+    let fits = |start: usize, step: isize| {
+        let end = start as isize + step * (in_a_row as isize - 1);
+        end >= 0 && end < size as isize
+    };
+
+    let mut lines = Vec::new();
+    for row in 0..size {
+        for column in 0..size {
+            for (row_step, column_step) in &directions {
+                if !fits(row, *row_step) || !fits(column, *column_step) {
+                    continue;
+                }
+                let mut line = vec![0; words];
+                for i in 0..in_a_row as isize {
+                    let r = (row as isize + row_step * i) as usize;
+                    let c = (column as isize + column_step * i) as usize;
+                    let (word, mask) = bit(r * size + c);
+                    line[word] |= mask;
+                }
+                lines.push(line);
+            }
+        }
+    }
+    lines
+}
+
+/// Counts the bits that two bitboards have in common.
+fn count_common(a: &[u64], b: &[u64]) -> u32 {
+    a.iter().zip(b).map(|(a, b)| (a & b).count_ones()).sum()
+}
+
+// Private block
+impl Grid {
+    /// Checks if a cell index is on the grid.
+    const fn in_bounds(&self, index: usize) -> bool {
+        index < self.size * self.size
+    }
+
+    /// Gets the bitboard of cells set by the given player, on a wide grid.
+    fn player_bits(set: &[u64], crosses: &[u64], player: Player) -> Vec<u64> {
+        set.iter()
+            .zip(crosses)
+            .map(|(s, c)| match player {
+                Player::X => s & c,
+                Player::O => s & !c,
+            })
+            .collect()
+    }
+
+    /// Finds every free cell that would complete a line for the given player.
+    fn winning_cells(&self, player: Player) -> Vec<usize> {
+        let (set, crosses, lines) = match &self.cells {
+            GridCells::Classic(board) => {
+                return self
+                    .legal_moves()
+                    .into_iter()
+                    .filter(|index| {
+                        let mut next = *board;
+                        next.set_cell(player, Cell::ALL[*index]);
+                        next.winner() == Some(player)
+                    })
+                    .collect();
+            }
+            GridCells::Wide {
+                set,
+                crosses,
+                lines,
+            } => (set, crosses, lines),
+        };
+
+        let own = Self::player_bits(set, crosses, player);
+        let needed = self.in_a_row as u32 - 1;
+        let mut cells: Vec<usize> = lines
+            .iter()
+            .filter(|line| count_common(line, &own) == needed && count_common(line, set) == needed)
+            .filter_map(|line| {
+                line.iter().zip(set).enumerate().find_map(|(word, (l, s))| {
+                    let free = l & !s;
+                    (free != 0).then(|| word * WORD_BITS + free.trailing_zeros() as usize)
+                })
+            })
+            .collect();
+        cells.sort_unstable();
+        cells.dedup();
+        cells
+    }
+}
+
+// Public block
+impl Grid {
+    /// Creates an empty `size` × `size` [Grid], won by setting `in_a_row` cells in a line.
+    ///
+    /// The classic 3×3, three-in-a-row game is stored as a [Board].
+    pub fn new(size: usize, in_a_row: usize) -> Result<Self, Error> {
+        if !(MIN_SIZE..=MAX_SIZE).contains(&size) {
+            return Err(Error::InvalidSize(size));
+        }
+        if !(1..=size).contains(&in_a_row) {
+            return Err(Error::InvalidInARow { in_a_row, size });
+        }
+
+        let cells = if size == 3 && in_a_row == 3 {
+            GridCells::Classic(Board::new())
+        } else {
+            let words = (size * size).div_ceil(WORD_BITS);
+            GridCells::Wide {
+                set: vec![0; words],
+                crosses: vec![0; words],
+                lines: win_lines(size, in_a_row),
+            }
+        };
+        Ok(Self {
+            size,
+            in_a_row,
+            cells,
+        })
+    }
+
+    /// Creates the classic 3×3 [Grid].
+    pub const fn classic() -> Self {
+        Self {
+            size: 3,
+            in_a_row: 3,
+            cells: GridCells::Classic(Board::new()),
+        }
+    }
+
+    /// Gets the length of a side of the grid.
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Gets how many cells in a line are needed to win.
+    pub const fn in_a_row(&self) -> usize {
+        self.in_a_row
+    }
+
+    /// Gets the number of cells on the grid.
+    pub const fn cell_count(&self) -> usize {
+        self.size * self.size
+    }
+
+    /// Gets the underlying [Board] of a classic grid.
+    pub const fn as_board(&self) -> Option<&Board> {
+        match &self.cells {
+            GridCells::Classic(board) => Some(board),
+            GridCells::Wide { .. } => None,
+        }
+    }
+
+    /// Creates an empty grid with the same size and win condition.
+    pub fn cleared(&self) -> Self {
+        Self::new(self.size, self.in_a_row).expect("existing grid has a valid size")
+    }
+
+    /// Parses a cell number, as typed by a player, into a cell index.
+    pub fn parse_cell(&self, input: &str) -> Result<usize, Error> {
+        if let GridCells::Classic(_) = self.cells {
+            let cell = Cell::try_from(input)?;
+            return Ok(cell.number() as usize - 1);
+        }
+        match input.parse::<usize>() {
+            Ok(number) if number >= 1 && self.in_bounds(number - 1) => Ok(number - 1),
+            _ => Err(Error::InvalidCell(input.to_owned())),
+        }
+    }
+
+    /// Gets the player who set the cell at the given index, if any.
+    pub fn owner(&self, index: usize) -> Option<Player> {
+        match &self.cells {
+            GridCells::Classic(board) => Cell::ALL.get(index).and_then(|c| board.owner(*c)),
+            GridCells::Wide { set, crosses, .. } => {
+                if !self.in_bounds(index) {
+                    return None;
+                }
+                let (word, mask) = bit(index);
+                if set[word] & mask == 0 {
+                    None
+                } else if crosses[word] & mask != 0 {
+                    Some(Player::X)
+                } else {
+                    Some(Player::O)
+                }
+            }
+        }
+    }
+
+    /// Lists the index of every cell that can still be played.
+    ///
+    /// Once the game is over there are no legal moves.
+    pub fn legal_moves(&self) -> Vec<usize> {
+        if self.outcome() != Outcome::InProgress {
+            return Vec::new();
+        }
+        (0..self.cell_count())
+            .filter(|index| self.owner(*index).is_none())
+            .collect()
+    }
+
+    /// Sets the cell at the given index for a given player, if the move is legal.
+    pub fn apply_move(&mut self, player: Player, index: usize) -> Result<(), Error> {
+        if !self.in_bounds(index) {
+            return Err(Error::InvalidCell((index + 1).to_string()));
+        }
+        if self.outcome() != Outcome::InProgress {
+            return Err(Error::GameOver);
+        }
+        if self.owner(index).is_some() {
+            return Err(Error::CellOccupied(index + 1));
+        }
+        match &mut self.cells {
+            GridCells::Classic(board) => board.set_cell(player, Cell::ALL[index]),
+            GridCells::Wide { set, crosses, .. } => {
+                let (word, mask) = bit(index);
+                set[word] |= mask;
+                if let Player::X = player {
+                    crosses[word] |= mask;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Gets the player who has completed a line, if any.
+    pub fn winner(&self) -> Option<Player> {
+        match &self.cells {
+            GridCells::Classic(board) => board.winner(),
+            GridCells::Wide {
+                set,
+                crosses,
+                lines,
+            } => lines
+                .iter()
+                .filter(|line| line.iter().zip(set).all(|(l, s)| l & s == *l))
+                .find_map(|line| {
+                    let crossed = count_common(line, crosses) as usize;
+                    if crossed == self.in_a_row {
+                        Some(Player::X)
+                    } else if crossed == 0 {
+                        Some(Player::O)
+                    } else {
+                        None
+                    }
+                }),
+        }
+    }
+
+    /// Lists every completed line, as the index of each of its cells, and who completed it.
+    pub fn winning_lines(&self) -> Vec<(Player, Vec<usize>)> {
+        let (set, crosses, lines) = match &self.cells {
+            GridCells::Classic(board) => {
+                return board
+                    .winning_patterns()
+                    .into_iter()
+                    .map(|(player, pattern)| {
+                        let mut line: Vec<usize> = pattern
+                            .cells()
+                            .iter()
+                            .map(|cell| cell.number() as usize - 1)
+                            .collect();
+                        line.sort_unstable();
+                        (player, line)
+                    })
+                    .collect();
+            }
+            GridCells::Wide {
+                set,
+                crosses,
+                lines,
+            } => (set, crosses, lines),
+        };
+        lines
+            .iter()
+            .filter(|line| line.iter().zip(set).all(|(l, s)| l & s == *l))
+            .filter_map(|line| {
+                let player = match count_common(line, crosses) as usize {
+                    0 => Player::O,
+                    crossed if crossed == self.in_a_row => Player::X,
+                    _ => return None,
+                };
+                let cells = (0..self.cell_count())
+                    .filter(|index| {
+                        let (word, mask) = bit(*index);
+                        line[word] & mask != 0
+                    })
+                    .collect();
+                Some((player, cells))
+            })
+            .collect()
+    }
+
+    /// Gets the [Outcome] of the game as it currently stands.
+    pub fn outcome(&self) -> Outcome {
+        match self.winner() {
+            Some(player) => Outcome::Win(player),
+            None if (0..self.cell_count()).all(|i| self.owner(i).is_some()) => Outcome::Draw,
+            None => Outcome::InProgress,
+        }
+    }
+
+    /// Picks a move for the given player at the given [Difficulty].
+    ///
+    /// A full search is only feasible on the classic board, so on larger grids
+    /// [`Difficulty::Hard`] and [`Difficulty::Perfect`] play like [`Difficulty::Medium`].
+    /// Returns [None] if the game is already over.
+    pub fn computer_move(
+        &self,
+        player: Player,
+        difficulty: Difficulty,
+        rng: &mut Rng,
+    ) -> Option<usize> {
+        if let GridCells::Classic(board) = &self.cells {
+            return board
+                .computer_move(player, difficulty, rng)
+                .map(|cell| cell.number() as usize - 1);
+        }
+        let legal_moves = self.legal_moves();
+        if legal_moves.is_empty() {
+            return None;
+        }
+        if difficulty != Difficulty::Easy {
+            let wins = self.winning_cells(player);
+            if !wins.is_empty() {
+                return rng.choose(&wins).copied();
+            }
+            let blocks = self.winning_cells(player.opponent());
+            if !blocks.is_empty() {
+                return rng.choose(&blocks).copied();
+            }
+        }
+        rng.choose(&legal_moves).copied()
+    }
+}
+
+// Public-Crate block
+impl Grid {
+    /// Lists the index of every cell in a completed line.
+    pub(crate) fn winning_line(&self) -> Vec<usize> {
+        let mut cells: Vec<usize> = self
+            .winning_lines()
+            .into_iter()
+            .flat_map(|(_, line)| line)
+            .collect();
+        cells.sort_unstable();
+        cells.dedup();
+        cells
+    }
+}
+
+impl From<Board> for Grid {
+    fn from(board: Board) -> Self {
+        Self {
+            size: 3,
+            in_a_row: 3,
+            cells: GridCells::Classic(board),
+        }
+    }
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,51 @@
+//! Handles how a grid is displayed to the players.
+//!
+//! The alternate format, `{:#}`, draws the cells of a winning line in brackets.
+
+use crate::{Grid, GridCells, Player};
+
+fn draw_top_line(size: usize) -> String {
+    format!("{}\n", "-".repeat(4 * size + 1))
+}
+
+fn draw_cell(mark: char, highlighted: bool) -> String {
+    if highlighted {
+        format!("[{}]|", mark)
+    } else {
+        format!(" {} |", mark)
+    }
+}
+
+impl std::fmt::Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let GridCells::Classic(board) = &self.cells {
+            return match f.alternate() {
+                true => write!(f, "{:#}", board),
+                false => write!(f, "{}", board),
+            };
+        }
+        let winning_line = if f.alternate() {
+            self.winning_line()
+        } else {
+            Vec::new()
+        };
+
+        let mut output_string = draw_top_line(self.size);
+        for row in (0..self.size).rev() {
+            output_string += "|";
+            for column in 0..self.size {
+                let index = row * self.size + column;
+                let mark = match self.owner(index) {
+                    Some(Player::X) => 'X',
+                    Some(Player::O) => 'O',
+                    None => 'E',
+                };
+                output_string += &draw_cell(mark, winning_line.contains(&index));
+            }
+            output_string += "\n";
+            output_string += &draw_top_line(self.size);
+        }
+
+        write!(f, "{}", output_string)
+    }
+}
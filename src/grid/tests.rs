@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod grid_tests {
+    use crate::{Board, Difficulty, Grid, Outcome, Player, Rng};
+
+    fn grid_from(size: usize, in_a_row: usize, moves: &[(Player, usize)]) -> Grid {
+        let mut grid = Grid::new(size, in_a_row).unwrap();
+        for (player, index) in moves {
+            grid.apply_move(*player, *index).unwrap();
+        }
+        grid
+    }
+
+    #[test]
+    fn classic_grid_uses_board_fast_path() {
+        assert_eq!(Some(&Board::new()), Grid::new(3, 3).unwrap().as_board());
+        assert_eq!(Some(&Board::new()), Grid::classic().as_board());
+        assert_eq!(None, Grid::new(3, 2).unwrap().as_board());
+        assert_eq!(None, Grid::new(4, 3).unwrap().as_board());
+    }
+
+    #[test]
+    fn invalid_sizes_are_rejected() {
+        assert!(Grid::new(2, 2).is_err());
+        assert!(Grid::new(20, 5).is_err());
+        assert!(Grid::new(4, 5).is_err());
+        assert!(Grid::new(4, 0).is_err());
+    }
+
+    #[test]
+    fn wide_grid_spans_multiple_words() {
+        let mut grid = Grid::new(15, 5).unwrap();
+        grid.apply_move(Player::X, 224).unwrap();
+        grid.apply_move(Player::O, 63).unwrap();
+        grid.apply_move(Player::X, 64).unwrap();
+        assert_eq!(Some(Player::X), grid.owner(224));
+        assert_eq!(Some(Player::O), grid.owner(63));
+        assert_eq!(Some(Player::X), grid.owner(64));
+        assert_eq!(None, grid.owner(65));
+        assert_eq!(222, grid.legal_moves().len());
+        assert!(grid.apply_move(Player::O, 224).is_err());
+        assert!(grid.apply_move(Player::O, 225).is_err());
+    }
+
+    #[test]
+    fn rows_columns_and_diagonals_win() {
+        let lines: [&[usize]; 4] = [
+            &[0, 1, 2, 3],
+            &[1, 5, 9, 13],
+            &[0, 5, 10, 15],
+            &[3, 6, 9, 12],
+        ];
+        for line in &lines {
+            let moves: Vec<(Player, usize)> = line.iter().map(|i| (Player::O, *i)).collect();
+            let grid = grid_from(4, 4, &moves);
+            assert_eq!(Outcome::Win(Player::O), grid.outcome(), "\n{}", grid);
+        }
+    }
+
+    #[test]
+    fn broken_line_does_not_win() {
+        let grid = grid_from(
+            5,
+            4,
+            &[
+                (Player::X, 0),
+                (Player::X, 1),
+                (Player::O, 2),
+                (Player::X, 3),
+                (Player::X, 4),
+            ],
+        );
+        assert_eq!(Outcome::InProgress, grid.outcome());
+    }
+
+    #[test]
+    fn shorter_lines_win_on_bigger_grids() {
+        let grid = grid_from(
+            15,
+            5,
+            &[
+                (Player::X, 112),
+                (Player::X, 128),
+                (Player::X, 144),
+                (Player::X, 160),
+                (Player::X, 176),
+            ],
+        );
+        assert_eq!(Some(Player::X), grid.winner());
+        assert!(grid.legal_moves().is_empty());
+    }
+
+    #[test]
+    fn full_grid_without_line_is_a_draw() {
+        let mut grid = Grid::new(4, 4).unwrap();
+        // Alternate pairs of columns per row, so no row, column or diagonal is complete.
+        for index in 0..16 {
+            let row = index / 4;
+            let column = index % 4;
+            let player = if (column / 2 + row) % 2 == 0 {
+                Player::X
+            } else {
+                Player::O
+            };
+            grid.apply_move(player, index).unwrap();
+        }
+        assert_eq!(Outcome::Draw, grid.outcome(), "\n{}", grid);
+    }
+
+    #[test]
+    fn parse_cell_checks_bounds() {
+        let grid = Grid::new(4, 3).unwrap();
+        assert_eq!(0, grid.parse_cell("1").unwrap());
+        assert_eq!(15, grid.parse_cell("16").unwrap());
+        assert!(grid.parse_cell("0").is_err());
+        assert!(grid.parse_cell("17").is_err());
+        assert!(grid.parse_cell("a").is_err());
+        assert_eq!(8, Grid::classic().parse_cell("9").unwrap());
+        assert!(Grid::classic().parse_cell("10").is_err());
+    }
+
+    #[test]
+    fn computer_takes_wins_and_blocks_on_wide_grid() {
+        let mut rng = Rng::new(11);
+        let grid = grid_from(
+            5,
+            4,
+            &[
+                (Player::X, 6),
+                (Player::X, 7),
+                (Player::X, 8),
+                (Player::O, 20),
+                (Player::O, 21),
+                (Player::O, 22),
+            ],
+        );
+        for _ in 0..10 {
+            let win = grid.computer_move(Player::X, Difficulty::Medium, &mut rng);
+            assert!(win == Some(5) || win == Some(9));
+            assert_eq!(
+                Some(23),
+                grid.computer_move(Player::O, Difficulty::Perfect, &mut rng)
+            );
+        }
+    }
+
+    #[test]
+    fn winning_line_lists_completed_cells() {
+        let grid = grid_from(
+            5,
+            4,
+            &[
+                (Player::O, 0),
+                (Player::O, 6),
+                (Player::O, 12),
+                (Player::O, 18),
+            ],
+        );
+        assert_eq!(vec![0, 6, 12, 18], grid.winning_line());
+        assert_eq!(vec![(Player::O, vec![0, 6, 12, 18])], grid.winning_lines());
+        assert!(format!("{:#}", grid).ends_with("|[O]| E | E | E | E |\n---------------------\n"));
+        assert!(grid_from(5, 4, &[(Player::O, 0), (Player::X, 6)])
+            .winning_line()
+            .is_empty());
+    }
+
+    #[test]
+    fn wide_grid_displays_rows_top_to_bottom() {
+        let grid = grid_from(4, 3, &[(Player::X, 0), (Player::O, 15)]);
+        assert_eq!(
+            "-----------------\n| E | E | E | O |\n-----------------\n| E | E | E | E |\n-----------------\n| E | E | E | E |\n-----------------\n| X | E | E | E |\n-----------------\n",
+            grid.to_string()
+        );
+    }
+}
@@ -1,28 +1,432 @@
 //! Welcome to Tic-Tac-Toe!
 //!
 //! This crate implements the schoolyard game using only bit patterns.
+//!
+//! Besides the interactive [play] loop, the crate exposes the [Board] so the
+//! game can be embedded in other tools:
+//!
+//! ```
+//! use tic_tac_toe_bit_patterns::{Board, Cell, Outcome, Player};
+//!
+//! let mut board = Board::new();
+//! board.apply_move(Player::X, Cell::TopLeft).unwrap();
+//! board.apply_move(Player::O, Cell::Centre).unwrap();
+//!
+//! assert_eq!(board.owner(Cell::TopLeft), Some(Player::X));
+//! assert_eq!(board.legal_moves().len(), 7);
+//! assert_eq!(board.outcome(), Outcome::InProgress);
+//! ```
+
+use std::collections::BTreeMap;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process::{Child, ChildStdin, ChildStdout};
 
 mod bit_patterns;
 mod board;
+mod elo;
+mod engine;
+mod error;
 pub mod game;
+pub mod grid;
+mod notation;
+mod options;
 mod player;
+mod playfield;
+mod positions;
+mod profile;
+mod protocol;
+mod rating;
+mod record;
+mod remote;
+mod rng;
+mod scoreboard;
+mod symmetry;
+mod threats;
+#[cfg(feature = "tui")]
+mod tui;
+mod ultimate;
+
+pub use bit_patterns::{Cell, WinPattern};
 
 /// Indicates who the active player is.
-#[derive(Clone, Copy)]
-enum Player {
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Player {
     O,
     X,
 }
 
 /// Represents the state of the game board.
-struct Board {
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Board {
     cells: u32,
 }
 
+/// One of the 8 ways to rotate or reflect a [Board] onto itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Symmetry {
+    Identity,
+    /// Rotates a quarter turn clockwise.
+    Rotate90,
+    Rotate180,
+    /// Rotates a quarter turn anticlockwise.
+    Rotate270,
+    /// Swaps the left and right columns.
+    MirrorLeftRight,
+    /// Swaps the top and bottom rows.
+    MirrorTopBottom,
+    /// Reflects across the diagonal from the top-left to the bottom-right.
+    MirrorMainDiagonal,
+    /// Reflects across the diagonal from the top-right to the bottom-left.
+    MirrorAntiDiagonal,
+}
+
+/// Represents a square board of any size, won by setting enough cells in a line.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid {
+    size: usize,
+    in_a_row: usize,
+    cells: GridCells,
+}
+
+/// The cells of a [Grid].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum GridCells {
+    /// The classic 3×3 board, stored in its 18-bit pattern.
+    Classic(Board),
+    /// Any other board, stored as multi-word bitboards alongside its generated win lines.
+    Wide {
+        set: Vec<u64>,
+        crosses: Vec<u64>,
+        lines: Vec<Vec<u64>>,
+    },
+}
+
+/// Represents a game of Ultimate Tic-Tac-Toe: a 3×3 grid of 3×3 [Board]s.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ultimate {
+    boards: [Board; 9],
+    meta: Board,
+    forced: Option<Cell>,
+}
+
+/// Describes the wrong character found while parsing a [Board] from its compact notation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotationError {
+    position: usize,
+    found: Option<char>,
+    expected: &'static str,
+}
+
+/// Everything that can go wrong while playing Tic-Tac-Toe.
+#[derive(Debug)]
+pub enum Error {
+    /// The input does not name a cell.
+    InvalidCell(String),
+    /// The cell with the given number has already been set.
+    CellOccupied(usize),
+    /// The game has already been won or drawn.
+    GameOver,
+    /// The sub-board of an [Ultimate] game has already been won or drawn.
+    SubBoardDecided(Cell),
+    /// The move must be played in the given sub-board of an [Ultimate] game.
+    WrongSubBoard(Cell),
+    /// A [Grid] cannot have sides of the given length.
+    InvalidSize(usize),
+    /// The given number of cells in a row does not fit on a [Grid] of the given size.
+    InvalidInARow { in_a_row: usize, size: usize },
+    /// The compact notation of a [Board] is wrong.
+    Notation(NotationError),
+    /// The given file is not a valid game record, for the given reason.
+    InvalidRecord(String, String),
+    /// The given file is not a valid profiles file, for the given reason.
+    InvalidProfiles(String, String),
+    /// There is no move to undo.
+    NothingToUndo,
+    /// There is no move to redo.
+    NothingToRedo,
+    /// The game could not be saved to the given file.
+    Save(String, io::Error),
+    /// The game could not be loaded from the given file.
+    Load(String, io::Error),
+    /// Reading or writing the game failed.
+    Io(io::Error),
+    /// The players ran out of input.
+    Eof,
+    /// The remote player or engine sent a message that is not part of its protocol.
+    Protocol(String),
+    /// The remote player sent a move out of step with the local game.
+    Desync { expected: usize, found: usize },
+    /// The remote player sent a move that is illegal on the local board.
+    IllegalRemoteMove(Box<Error>),
+    /// The remote player left the game.
+    Disconnected,
+    /// The command cannot be used in a network game.
+    NetworkGame,
+    /// Moves can only be rated on the classic board.
+    NoHints,
+    /// The external engine sent a move that is illegal on the board.
+    IllegalEngineMove(Box<Error>),
+    /// The external engine stopped running.
+    EngineExited,
+}
+
+/// Counts of every position that can be reached in a game where X moves first.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PositionCounts {
+    /// Every reachable position, including the empty board.
+    pub total: usize,
+    /// Positions left once symmetric copies are counted as one.
+    pub canonical: usize,
+    /// Positions by how many cells have been set.
+    pub by_move: [usize; 10],
+    /// Positions that X has won.
+    pub x_wins: usize,
+    /// Positions that O has won.
+    pub o_wins: usize,
+    /// Full positions that nobody has won.
+    pub draws: usize,
+    /// Unfinished positions where X is to move.
+    pub x_to_move: usize,
+    /// Unfinished positions where O is to move.
+    pub o_to_move: usize,
+}
+
+/// The result of a game as it stands on a [Board].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    /// Nobody has won and there are still free cells.
+    InProgress,
+    /// The given player has completed a [WinPattern].
+    Win(Player),
+    /// Every cell is set and nobody has won.
+    Draw,
+}
+
+/// How a move turns out if both players play perfectly from then on.
+///
+/// Ratings are ordered from worst to best for the player making the move.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rating {
+    /// The player wins after the given number of moves, counting this one.
+    Win(usize),
+    Draw,
+    /// The player loses after the given number of moves, counting this one.
+    Loss(usize),
+}
+
+/// How a finished game went for one player.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum GameResult {
+    Win,
+    Draw,
+    Loss,
+}
+
+/// A named player, computer difficulty or engine whose results are kept in a local file from one session to the next.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Profile {
+    pub name: String,
+    /// The player's Elo rating, which rises with every win and falls with every loss,
+    /// by more when the result is an upset.
+    pub rating: i32,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    /// Moves made by both players over every game, to find the average length of a game.
+    pub moves: usize,
+    /// How often each cell, named the way players type it, was the player's first move.
+    pub openings: BTreeMap<String, usize>,
+    /// The result of the latest game, and how many games in a row have ended that way.
+    pub streak: Option<(GameResult, usize)>,
+    pub longest_win_streak: usize,
+    pub longest_loss_streak: usize,
+}
+
+/// Every [Profile] saved in a profiles file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Profiles {
+    profiles: Vec<Profile>,
+}
+
+/// How well the computer plays.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Difficulty {
+    /// Plays a random legal move.
+    Easy,
+    /// Takes immediate wins and blocks immediate losses, otherwise plays randomly.
+    Medium,
+    /// Searches a few moves ahead.
+    Hard,
+    /// Searches every game to the end and never loses.
+    Perfect,
+}
+
+/// The rules and board a game is played with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Variant {
+    /// Lines win, on the classic board or a bigger one.
+    Classic,
+    /// A 3x3 grid of 3x3 boards.
+    Ultimate,
+    /// The classic board, where completing a line loses.
+    Misere,
+}
+
+/// How the starting player is chosen for each game in a session.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StartPolicy {
+    /// Asks the players before every game.
+    #[default]
+    Ask,
+    /// Picks a player at random before every game.
+    Random,
+    /// The given player starts every game.
+    Always(Player),
+    /// The first game starts at random, then the players take turns to start.
+    Alternate,
+    /// The first game starts at random, then the loser of each game starts the next.
+    /// After a draw the players take turns, as with [`StartPolicy::Alternate`].
+    LoserStarts,
+}
+
+/// Who plays against the local player.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Opponent {
+    /// Another player at the same keyboard.
+    Human,
+    /// The built-in computer, playing at the given [Difficulty].
+    Computer(Difficulty),
+    /// An external engine, started with the given command.
+    Engine(String),
+    /// A remote player, who joins a game hosted on the given port.
+    Host(u16),
+    /// A remote player, who hosts the game at the given address.
+    Join(String),
+}
+
+/// Settings chosen up front, such as on the command line, so the game does not ask for them.
+///
+/// Anything left as [None] is asked for as usual.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Options {
+    pub variant: Option<Variant>,
+    /// The length of the sides of a [`Variant::Classic`] board.
+    pub size: Option<usize>,
+    /// How many cells in a row win on a [`Variant::Classic`] board, which defaults to its size.
+    pub in_a_row: Option<usize>,
+    /// How the starting player is chosen for each game.
+    pub start: StartPolicy,
+    pub opponent: Option<Opponent>,
+    /// The side the local player takes against the computer or an engine.
+    pub side: Option<Player>,
+    /// Seeds every random choice, including the computer's moves and the starting player,
+    /// so a whole session can be repeated.
+    pub seed: Option<u64>,
+    /// Ends the session after one game instead of offering another.
+    pub no_replay: bool,
+    /// Plays a match of at most this many games, which ends as soon as a player has won most of them.
+    pub best_of: Option<usize>,
+    /// The name of the [Profile] that plays as O, whose results are saved after every game.
+    pub profile_o: Option<String>,
+    /// The name of the [Profile] that plays as X, whose results are saved after every game.
+    pub profile_x: Option<String>,
+    /// The file the profiles are kept in, which defaults to `profiles.txt`.
+    pub profiles: Option<String>,
+}
+
+/// A small, seedable pseudo-random number generator.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+/// Decides who makes the moves for a [Player].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Controller {
+    Human,
+    Computer(Difficulty),
+    /// A player on another machine, whose moves arrive over the network.
+    Remote,
+    /// An external program that plays through the engine protocol.
+    Engine,
+}
+
+/// The board a [Game] is played on.
+#[derive(Clone, Debug)]
+pub(crate) enum Playfield {
+    Grid(Grid),
+    Ultimate(Ultimate),
+    /// The classic grid, where completing a line loses instead of wins.
+    Misere(Grid),
+}
+
+/// A record of a game that can be saved to and loaded from text.
+#[derive(Clone, Debug)]
+pub(crate) struct GameRecord {
+    /// An empty playfield of the kind the game was played on.
+    playfield: Playfield,
+    player_o: Controller,
+    player_x: Controller,
+    date: String,
+    start: Player,
+    /// The cell index of every move, in order.
+    moves: Vec<usize>,
+}
+
+/// A move made during a [Game].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Move {
+    /// The position of the move in the game, starting from 1.
+    number: usize,
+    player: Player,
+    /// The index of the cell on the [Playfield].
+    cell: usize,
+}
+
+/// A connection to the other instance in a network game.
+pub(crate) struct Remote {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+/// An external engine, running as a child process, that plays through the engine protocol.
+pub(crate) struct EngineProcess {
+    child: Child,
+    reader: BufReader<ChildStdout>,
+    writer: Option<ChildStdin>,
+}
+
+/// The results of every game finished in a session, kept across replays.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Scoreboard {
+    o_wins: usize,
+    x_wins: usize,
+    draws: usize,
+}
+
 /// Represents the total game state
-struct Game {
-    board: Board,
+struct Game<R, W> {
+    board: Playfield,
     current_player: Player,
+    /// The player who started the current game, once one has been chosen.
+    starter: Option<Player>,
+    history: Vec<Move>,
+    undone: Vec<Move>,
+    player_o: Controller,
+    player_x: Controller,
+    rng: Rng,
+    /// The other instance, if this is a network game.
+    remote: Option<Remote>,
+    /// The external engine, if one plays in this game.
+    engine: Option<EngineProcess>,
+    /// The settings chosen before the game, instead of asking for them.
+    options: Options,
+    scoreboard: Scoreboard,
+    /// Every saved profile, loaded before play if any player has one.
+    profiles: Option<Profiles>,
+    reader: R,
+    writer: W,
 }
 
 /// A helper enum for handling the end game state.
@@ -32,21 +436,292 @@ pub(crate) enum EndState {
     Continue,
 }
 
-/// Runs the game loop
-pub fn play() {
-    let mut game = Game::new();
-    println!("Welcome to Tic-Tac-Toe!");
-    println!("{} Begins.", game.current_player);
+/// What may follow a finished game.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum NextGame {
+    /// The players choose whether to play again.
+    Offer,
+    /// The match goes on with another game.
+    Match,
+    /// The session is over.
+    Stop,
+}
+
+/// Runs the game loop over standard input and output.
+pub fn play() -> Result<(), Error> {
+    run(&Options::default())
+}
+
+/// Runs the game loop, reading player input from `reader` and writing the game to `writer`.
+///
+/// Running out of input ends the game cleanly.
+pub fn play_with<R: BufRead, W: Write>(reader: R, writer: W) -> Result<(), Error> {
+    run_with(&Options::default(), reader, writer)
+}
+
+/// Runs a session with the given [Options] over standard input and output.
+pub fn run(options: &Options) -> Result<(), Error> {
+    let stdin = io::stdin();
+    run_with(options, stdin.lock(), io::stdout())
+}
+
+/// Runs a session with the given [Options], reading player input from `reader` and writing the game to `writer`.
+///
+/// The [Opponent] decides whether the game is local, hosted, joined or played against an engine.
+pub fn run_with<R: BufRead, W: Write>(
+    options: &Options,
+    reader: R,
+    mut writer: W,
+) -> Result<(), Error> {
+    let result = start_game(options, reader, &mut writer).and_then(run_game);
+    end_cleanly_on_eof(result, writer)
+}
+
+/// Runs a session with the given [Options] in a full-screen terminal interface,
+/// once it has been set up over standard input and output.
+#[cfg(feature = "tui")]
+pub fn play_tui(options: &Options) -> Result<(), Error> {
+    let stdin = io::stdin();
+    let result = start_game(options, stdin.lock(), io::stdout()).and_then(|game| {
+        let mut game = game.with_writer(io::sink());
+        let result = game.run_full_screen();
+        if game.scoreboard.games() > 0 {
+            let summary = game.scoreboard.summary(|player| game.name(player));
+            write!(io::stdout().lock(), "{}", summary)?;
+        }
+        result
+    });
+    match result {
+        Ok(()) => {
+            writeln!(io::stdout().lock(), "Thank you for playing Tic-Tac-Toe!")?;
+            Ok(())
+        }
+        result => end_cleanly_on_eof(result, io::stdout().lock()),
+    }
+}
+
+/// Counts every reachable position and writes the counts to standard output.
+pub fn count_positions() -> Result<(), Error> {
+    write!(io::stdout(), "{}", PositionCounts::new())?;
+    Ok(())
+}
+
+/// Writes every [Profile] in the profiles file named by the [Options] to standard output.
+pub fn show_profiles(options: &Options) -> Result<(), Error> {
+    let profiles = Profiles::load(options.profiles_path())?;
+    let mut stdout = io::stdout().lock();
+    if profiles.profiles().is_empty() {
+        writeln!(stdout, "No profiles have been saved yet.")?;
+    }
+    for profile in profiles.profiles() {
+        write!(stdout, "{}", profile)?;
+    }
+    Ok(())
+}
+
+/// Writes every [Profile] in the profiles file named by the [Options] to standard output,
+/// from the highest rating down.
+pub fn show_leaderboard(options: &Options) -> Result<(), Error> {
+    let profiles = Profiles::load(options.profiles_path())?;
+    let mut stdout = io::stdout().lock();
+    if profiles.profiles().is_empty() {
+        writeln!(stdout, "No profiles have been saved yet.")?;
+    } else {
+        write!(stdout, "{}", profiles.leaderboard())?;
+    }
+    Ok(())
+}
+
+/// Hosts a network game on the given port over standard input and output.
+pub fn host(port: u16) -> Result<(), Error> {
+    run(&Options {
+        opponent: Some(Opponent::Host(port)),
+        ..Options::default()
+    })
+}
+
+/// Hosts a network game, playing as Player X against whoever connects to `listener` first.
+///
+/// The host chooses the board and who starts.
+pub fn host_with<R: BufRead, W: Write>(
+    listener: &TcpListener,
+    reader: R,
+    writer: W,
+) -> Result<(), Error> {
+    host_on(listener, &Options::default(), reader, writer)
+}
+
+/// Joins the network game hosted at the given address over standard input and output.
+pub fn join(address: &str) -> Result<(), Error> {
+    run(&Options {
+        opponent: Some(Opponent::Join(address.to_owned())),
+        ..Options::default()
+    })
+}
+
+/// Joins a network game through a connection to the host, playing as Player O.
+pub fn join_with<R: BufRead, W: Write>(
+    stream: TcpStream,
+    reader: R,
+    writer: W,
+) -> Result<(), Error> {
+    join_on(stream, &Options::default(), reader, writer)
+}
+
+/// Runs as an engine over standard input and output, playing perfectly.
+pub fn run_engine() -> Result<(), Error> {
+    let stdin = io::stdin();
+    run_engine_with(stdin.lock(), io::stdout())
+}
+
+/// Answers engine protocol commands from `reader` on `writer` until told to quit.
+pub fn run_engine_with<R: BufRead, W: Write>(reader: R, writer: W) -> Result<(), Error> {
+    protocol::serve(reader, writer, Difficulty::Perfect)
+}
+
+/// Runs the game loop over standard input and output against the engine started by `command`.
+pub fn play_against(command: &str) -> Result<(), Error> {
+    let stdin = io::stdin();
+    play_against_with(command, stdin.lock(), io::stdout())
+}
+
+/// Runs the game loop, with the engine started by `command` playing in place of the computer.
+pub fn play_against_with<R: BufRead, W: Write>(
+    command: &str,
+    reader: R,
+    writer: W,
+) -> Result<(), Error> {
+    let options = Options {
+        opponent: Some(Opponent::Engine(command.to_owned())),
+        ..Options::default()
+    };
+    run_with(&options, reader, writer)
+}
+
+/// Sets up a game against the [Opponent] in the [Options], first connecting to it
+/// if it plays over the network or as an engine.
+fn start_game<R: BufRead, W: Write>(
+    options: &Options,
+    reader: R,
+    writer: W,
+) -> Result<Game<R, W>, Error> {
+    match &options.opponent {
+        Some(Opponent::Host(port)) => {
+            let listener = TcpListener::bind(("0.0.0.0", *port))?;
+            hosted_game(&listener, options, reader, writer)
+        }
+        Some(Opponent::Join(address)) => {
+            let stream = TcpStream::connect(address)?;
+            joined_game(stream, options, reader, writer)
+        }
+        Some(Opponent::Engine(command)) => {
+            let engine = EngineProcess::spawn(command)?;
+            Game::against(reader, writer, options, engine)
+        }
+        _ => Game::new(reader, writer, options),
+    }
+}
+
+/// Sets up a network game with the given [Options] for whoever connects to `listener` first.
+fn hosted_game<R: BufRead, W: Write>(
+    listener: &TcpListener,
+    options: &Options,
+    reader: R,
+    mut writer: W,
+) -> Result<Game<R, W>, Error> {
+    writeln!(
+        writer,
+        "Waiting for a player to join on port {}",
+        listener.local_addr()?.port()
+    )?;
+    let (stream, address) = listener.accept()?;
+    writeln!(writer, "{} has joined the game", address)?;
+    Remote::new(stream).and_then(|remote| Game::hosted(reader, writer, options, remote))
+}
+
+/// Sets up a network game with the given [Options] through a connection to the host.
+fn joined_game<R: BufRead, W: Write>(
+    stream: TcpStream,
+    options: &Options,
+    reader: R,
+    writer: W,
+) -> Result<Game<R, W>, Error> {
+    Remote::new(stream).and_then(|remote| Game::joined(reader, writer, options, remote))
+}
+
+/// Hosts a network game with the given [Options] for whoever connects to `listener` first.
+fn host_on<R: BufRead, W: Write>(
+    listener: &TcpListener,
+    options: &Options,
+    reader: R,
+    mut writer: W,
+) -> Result<(), Error> {
+    let result = hosted_game(listener, options, reader, &mut writer).and_then(run_game);
+    end_cleanly_on_eof(result, writer)
+}
+
+/// Joins a network game with the given [Options] through a connection to the host.
+fn join_on<R: BufRead, W: Write>(
+    stream: TcpStream,
+    options: &Options,
+    reader: R,
+    mut writer: W,
+) -> Result<(), Error> {
+    let result = joined_game(stream, options, reader, &mut writer).and_then(run_game);
+    end_cleanly_on_eof(result, writer)
+}
+
+/// Says goodbye instead of failing when the players run out of input.
+fn end_cleanly_on_eof<W: Write>(result: Result<(), Error>, mut writer: W) -> Result<(), Error> {
+    match result {
+        Err(Error::Eof) => {
+            writeln!(writer)?;
+            writeln!(writer, "Thank you for playing Tic-Tac-Toe!")?;
+            Ok(())
+        }
+        result => result,
+    }
+}
+
+/// Runs the game loop until the players stop playing.
+fn run_game<R: BufRead, W: Write>(mut game: Game<R, W>) -> Result<(), Error> {
+    writeln!(game.writer, "Welcome to Tic-Tac-Toe!")?;
+    writeln!(game.writer, "{} Begins.", game.name(game.current_player))?;
+    if game.remote.is_none() {
+        writeln!(
+            game.writer,
+            "Enter U instead of a cell to undo a move, or R to redo it."
+        )?;
+        writeln!(
+            game.writer,
+            "Enter save or load followed by a file name to save the game or resume another."
+        )?;
+    }
+    if game.board.supports_hints() {
+        writeln!(
+            game.writer,
+            "Enter ? or hint to see how every move would turn out."
+        )?;
+    }
+
+    let result = play_games(&mut game);
+    if game.scoreboard.games() > 0 {
+        let summary = game.scoreboard.summary(|player| game.name(player));
+        write!(game.writer, "{}", summary)?;
+    }
+    result?;
+    writeln!(game.writer, "Thank you for playing Tic-Tac-Toe!")?;
+    Ok(())
+}
 
+/// Plays moves, and games, until the players stop playing.
+fn play_games<R: BufRead, W: Write>(game: &mut Game<R, W>) -> Result<(), Error> {
     loop {
-        game.board.select_cell(game.current_player);
-        println!("{}", game.board);
-        match game.on_end() {
-            EndState::End => break,
-            EndState::Replay => continue,
-            EndState::Continue => (),
+        game.select_cell()?;
+        writeln!(game.writer, "{:#}", game.board)?;
+        match game.on_end()? {
+            EndState::End => return Ok(()),
+            EndState::Replay | EndState::Continue => (),
         }
-        game.current_player.swap();
     }
-    println!("Thank you for playing Tic-Tac-Toe!");
 }
@@ -1,5 +1,69 @@
-use tic_tac_toe_bit_patterns::play;
+#[cfg(feature = "tui")]
+use tic_tac_toe_bit_patterns::play_tui;
+use tic_tac_toe_bit_patterns::{
+    count_positions, run, run_engine, show_leaderboard, show_profiles, Options,
+};
 
 fn main() {
-    play();
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let result = match args.as_slice() {
+        #[cfg(feature = "tui")]
+        args if args.contains(&"--tui") => {
+            let args: Vec<&str> = args.iter().copied().filter(|a| *a != "--tui").collect();
+            match Options::from_args(&args) {
+                Some(options) => play_tui(&options),
+                None => usage(),
+            }
+        }
+        ["--engine"] => run_engine(),
+        ["--count-positions"] => count_positions(),
+        ["--stats", args @ ..] => match Options::from_args(args) {
+            Some(options) => show_profiles(&options),
+            None => usage(),
+        },
+        ["--leaderboard", args @ ..] => match Options::from_args(args) {
+            Some(options) => show_leaderboard(&options),
+            None => usage(),
+        },
+        args => match Options::from_args(args) {
+            Some(options) => run(&options),
+            None => usage(),
+        },
+    };
+    if let Err(e) = result {
+        eprintln!("Sorry, {}.", e);
+        std::process::exit(1);
+    }
+}
+
+/// Explains the command-line arguments and exits.
+fn usage() -> ! {
+    eprintln!("Usage: tic_tac_toe_bit_patterns [--tui] [OPTIONS]");
+    eprintln!("       tic_tac_toe_bit_patterns --engine | --count-positions");
+    eprintln!("       tic_tac_toe_bit_patterns --stats | --leaderboard [--profiles FILE]");
+    eprintln!();
+    eprintln!("Options:");
+    eprintln!("  --variant classic|ultimate|misere  the rules to play by");
+    eprintln!("  --size N                           the size of a classic board, from 3-19");
+    eprintln!("  --in-a-row N                       how many cells in a row win a classic board");
+    eprintln!("  --start X|O|ask|random|alternate|loser");
+    eprintln!("                                     who moves first in each game");
+    eprintln!("  --opponent human|easy|medium|hard|perfect");
+    eprintln!(
+        "                                     another player or the computer at a difficulty"
+    );
+    eprintln!("  --against COMMAND                  play against an engine started by COMMAND");
+    eprintln!("  --host PORT                        host a network game on PORT");
+    eprintln!("  --join ADDRESS                     join the network game at ADDRESS");
+    eprintln!(
+        "  --side X|O                         the side you play against the computer or an engine"
+    );
+    eprintln!("  --seed N                           repeat every random choice in the session");
+    eprintln!("  --no-replay                        exit after one game");
+    eprintln!("  --profile-o NAME, --profile-x NAME");
+    eprintln!("                                     save each game to a named profile");
+    eprintln!("  --profiles FILE                    the file profiles are kept in (profiles.txt)");
+    eprintln!("  --best-of N                        play a match that ends once a player wins most of N games");
+    std::process::exit(2);
 }
@@ -0,0 +1,137 @@
+//! Provides a compact notation for a [Board] and the player to move.
+//!
+//! The notation lists the rows from top to bottom, separated by `/`, then a
+//! space and the player to move in lower case. Each cell is `X`, `O` or `.`
+//! when it is free, so `XO./.X./..O x` is:
+//!
+//! ```text
+//! | X | O | E |
+//! | E | X | E |
+//! | E | E | O |
+//! ```
+//! with Player X to move.
+
+use crate::bit_patterns::Cell;
+use crate::{Board, NotationError, Player};
+
+/// The cells of each row, from top to bottom and left to right.
+const ROWS: [[Cell; 3]; 3] = [
+    [Cell::TopLeft, Cell::TopCentre, Cell::TopRight],
+    [Cell::CentreLeft, Cell::Centre, Cell::CentreRight],
+    [Cell::BottomLeft, Cell::BottomCentre, Cell::BottomRight],
+];
+
+// Public block
+impl Board {
+    /// Formats the board and the player to move in the compact notation.
+    pub fn to_notation(&self, to_move: Player) -> String {
+        let rows: Vec<String> = ROWS
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| match self.owner(*cell) {
+                        Some(Player::X) => 'X',
+                        Some(Player::O) => 'O',
+                        None => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+        let side = match to_move {
+            Player::X => 'x',
+            Player::O => 'o',
+        };
+        format!("{} {}", rows.join("/"), side)
+    }
+
+    /// Parses a board and the player to move from the compact notation.
+    pub fn from_notation(notation: &str) -> Result<(Self, Player), NotationError> {
+        let mut chars = notation.chars().enumerate();
+        let mut expect = |expected: &'static str| {
+            let next = chars.next();
+            let position = next.map_or(notation.chars().count(), |(i, _)| i);
+            (
+                next.map(|(_, c)| c),
+                NotationError::new(position, next.map(|(_, c)| c), expected),
+            )
+        };
+
+        let mut board = Self::new();
+        for (i, row) in ROWS.iter().enumerate() {
+            for cell in row {
+                match expect("X, O or .") {
+                    (Some('X'), _) => board.set_cell(Player::X, *cell),
+                    (Some('O'), _) => board.set_cell(Player::O, *cell),
+                    (Some('.'), _) => (),
+                    (_, error) => return Err(error),
+                }
+            }
+            let (separator, expected) = if i < 2 { ('/', "/") } else { (' ', "a space") };
+            match expect(expected) {
+                (Some(c), _) if c == separator => (),
+                (_, error) => return Err(error),
+            }
+        }
+        let to_move = match expect("x or o") {
+            (Some('x'), _) => Player::X,
+            (Some('o'), _) => Player::O,
+            (_, error) => return Err(error),
+        };
+        match expect("the end of the notation") {
+            (None, _) => Ok((board, to_move)),
+            (_, error) => Err(error),
+        }
+    }
+}
+
+// Public-Crate block
+impl NotationError {
+    /// Creates an error for the character at `position`, or the end of the notation if `found` is [None].
+    pub(crate) const fn new(position: usize, found: Option<char>, expected: &'static str) -> Self {
+        Self {
+            position,
+            found,
+            expected,
+        }
+    }
+}
+
+// Public block
+impl NotationError {
+    /// Gets the position of the wrong character, counting from 0.
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Gets the wrong character, or [None] if the notation ended too soon.
+    pub const fn found(&self) -> Option<char> {
+        self.found
+    }
+
+    /// Describes what was expected instead.
+    pub const fn expected(&self) -> &'static str {
+        self.expected
+    }
+}
+
+impl std::fmt::Display for NotationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.found {
+            Some(c) => write!(
+                f,
+                "expected {} at character {} but found '{}'",
+                self.expected,
+                self.position + 1,
+                c
+            ),
+            None => write!(
+                f,
+                "expected {} at character {} but the notation ended",
+                self.expected,
+                self.position + 1
+            ),
+        }
+    }
+}
+
+impl std::error::Error for NotationError {}
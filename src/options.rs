@@ -0,0 +1,145 @@
+//! Provides functionality for the [Options] struct
+
+use crate::record::parse_player;
+use crate::{
+    Difficulty, Error, Grid, Opponent, Options, Playfield, StartPolicy, Ultimate, Variant,
+};
+
+/// The file profiles are kept in when the [Options] do not name one.
+const DEFAULT_PROFILES: &str = "profiles.txt";
+
+/// Checks a profile name can be kept on one line of a profiles file.
+fn parse_name(name: &str) -> Option<String> {
+    let name = name.trim();
+    (!name.is_empty() && !name.contains('\n')).then(|| name.to_owned())
+}
+
+/// Parses a [Difficulty] by name, ignoring case.
+fn parse_difficulty(name: &str) -> Option<Difficulty> {
+    match name.to_lowercase().as_str() {
+        "easy" => Some(Difficulty::Easy),
+        "medium" => Some(Difficulty::Medium),
+        "hard" => Some(Difficulty::Hard),
+        "perfect" => Some(Difficulty::Perfect),
+        _ => None,
+    }
+}
+
+/// Parses a [StartPolicy] by name, or a player to always start, ignoring case.
+fn parse_start_policy(name: &str) -> Option<StartPolicy> {
+    match name.to_lowercase().as_str() {
+        "ask" => Some(StartPolicy::Ask),
+        "random" => Some(StartPolicy::Random),
+        "alternate" => Some(StartPolicy::Alternate),
+        "loser" => Some(StartPolicy::LoserStarts),
+        _ => parse_player(&name.to_uppercase())
+            .ok()
+            .map(StartPolicy::Always),
+    }
+}
+
+/// Parses a [Variant] by name, ignoring case.
+fn parse_variant(name: &str) -> Option<Variant> {
+    match name.to_lowercase().as_str() {
+        "classic" => Some(Variant::Classic),
+        "ultimate" => Some(Variant::Ultimate),
+        "misere" | "misère" => Some(Variant::Misere),
+        _ => None,
+    }
+}
+
+// Public-Crate block
+impl Options {
+    /// Builds the playfield the options describe, or [None] if the players should choose.
+    pub(crate) fn playfield(&self) -> Result<Option<Playfield>, Error> {
+        let sized = self.size.is_some() || self.in_a_row.is_some();
+        match self.variant {
+            Some(Variant::Ultimate) => Ok(Some(Playfield::Ultimate(Ultimate::new()))),
+            Some(Variant::Misere) => Ok(Some(Playfield::Misere(Grid::classic()))),
+            None if !sized => Ok(None),
+            Some(Variant::Classic) | None => {
+                let size = self.size.unwrap_or(3);
+                let grid = Grid::new(size, self.in_a_row.unwrap_or(size))?;
+                Ok(Some(grid.into()))
+            }
+        }
+    }
+
+    /// Gets the file the profiles are kept in.
+    pub(crate) fn profiles_path(&self) -> &str {
+        self.profiles.as_deref().unwrap_or(DEFAULT_PROFILES)
+    }
+}
+
+// Public block
+impl Options {
+    /// Parses command-line arguments, such as `--start X --opponent perfect --no-replay`.
+    ///
+    /// Returns [None] if an argument is not understood, a value is missing,
+    /// a board size is given for a variant other than [`Variant::Classic`],
+    /// the cells in a row are not between 3 and the size of the board,
+    /// both players are given the same profile,
+    /// or a match is asked for without replays or over the network.
+    pub fn from_args<S: AsRef<str>>(args: &[S]) -> Option<Self> {
+        let mut options = Self::default();
+        let mut args = args.iter().map(AsRef::as_ref);
+        while let Some(flag) = args.next() {
+            if flag == "--no-replay" {
+                options.no_replay = true;
+                continue;
+            }
+            let value = args.next()?;
+            match flag {
+                "--variant" => options.variant = Some(parse_variant(value)?),
+                "--size" => options.size = Some(value.parse().ok()?),
+                "--in-a-row" => options.in_a_row = Some(value.parse().ok()?),
+                "--start" => options.start = parse_start_policy(value)?,
+                "--side" => options.side = Some(parse_player(&value.to_uppercase()).ok()?),
+                "--opponent" => {
+                    options.opponent = Some(match value.to_lowercase().as_str() {
+                        "human" => Opponent::Human,
+                        difficulty => Opponent::Computer(parse_difficulty(difficulty)?),
+                    })
+                }
+                "--against" => options.opponent = Some(Opponent::Engine(value.to_owned())),
+                "--host" => options.opponent = Some(Opponent::Host(value.parse().ok()?)),
+                "--join" => options.opponent = Some(Opponent::Join(value.to_owned())),
+                "--seed" => options.seed = Some(value.parse().ok()?),
+                "--profile-o" => options.profile_o = Some(parse_name(value)?),
+                "--profile-x" => options.profile_x = Some(parse_name(value)?),
+                "--profiles" => options.profiles = Some(value.to_owned()),
+                "--best-of" => options.best_of = Some(value.parse().ok().filter(|n| *n > 0)?),
+                _ => return None,
+            }
+        }
+        let size = options.size.unwrap_or(3);
+        if options
+            .in_a_row
+            .is_some_and(|in_a_row| !(3..=size).contains(&in_a_row))
+        {
+            return None;
+        }
+        let sized = options.size.is_some() || options.in_a_row.is_some();
+        if sized
+            && options
+                .variant
+                .is_some_and(|variant| variant != Variant::Classic)
+        {
+            return None;
+        }
+        if options.profile_o.is_some() && options.profile_o == options.profile_x {
+            return None;
+        }
+        let networked = matches!(
+            options.opponent,
+            Some(Opponent::Host(_)) | Some(Opponent::Join(_))
+        );
+        if options.best_of.is_some() && (options.no_replay || networked) {
+            return None;
+        }
+        Some(options)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod options_tests {
+    use crate::{Difficulty, Opponent, Options, Player, Playfield, StartPolicy, Variant};
+
+    #[test]
+    fn no_arguments_leave_everything_to_the_prompts() {
+        let options = Options::from_args::<&str>(&[]).unwrap();
+        assert_eq!(Options::default(), options);
+        assert!(options.playfield().unwrap().is_none());
+    }
+
+    #[test]
+    fn flags_fill_in_the_options() {
+        let options = Options::from_args(&[
+            "--start",
+            "x",
+            "--opponent",
+            "Perfect",
+            "--side",
+            "O",
+            "--seed",
+            "42",
+            "--no-replay",
+        ])
+        .unwrap();
+        assert_eq!(
+            Options {
+                start: StartPolicy::Always(Player::X),
+                opponent: Some(Opponent::Computer(Difficulty::Perfect)),
+                side: Some(Player::O),
+                seed: Some(42),
+                no_replay: true,
+                ..Options::default()
+            },
+            options
+        );
+    }
+
+    #[test]
+    fn opponents_can_be_engines_or_remote_players() {
+        assert_eq!(
+            Some(Opponent::Engine("python3 bot.py".to_owned())),
+            Options::from_args(&["--against", "python3 bot.py"])
+                .unwrap()
+                .opponent
+        );
+        assert_eq!(
+            Some(Opponent::Host(4000)),
+            Options::from_args(&["--host", "4000"]).unwrap().opponent
+        );
+        assert_eq!(
+            Some(Opponent::Human),
+            Options::from_args(&["--opponent", "human"])
+                .unwrap()
+                .opponent
+        );
+    }
+
+    #[test]
+    fn board_flags_build_the_playfield() {
+        let options = Options::from_args(&["--size", "5", "--in-a-row", "4"]).unwrap();
+        let playfield = options.playfield().unwrap().unwrap();
+        assert_eq!("5x5 4", playfield.name());
+        let options = Options::from_args(&["--variant", "misere"]).unwrap();
+        assert!(matches!(
+            options.playfield().unwrap(),
+            Some(Playfield::Misere(_))
+        ));
+        assert_eq!(
+            Some(Variant::Ultimate),
+            Options::from_args(&["--variant", "Ultimate"])
+                .unwrap()
+                .variant
+        );
+        let options = Options::from_args(&["--in-a-row", "3"]).unwrap();
+        assert_eq!("3x3", options.playfield().unwrap().unwrap().name());
+        let options = Options::from_args(&["--variant", "classic"]).unwrap();
+        assert_eq!("3x3", options.playfield().unwrap().unwrap().name());
+        assert!(Options::from_args(&["--size", "2"])
+            .unwrap()
+            .playfield()
+            .is_err());
+    }
+
+    #[test]
+    fn bad_arguments_are_rejected() {
+        assert_eq!(None, Options::from_args(&["--start"]));
+        assert_eq!(None, Options::from_args(&["--start", "Z"]));
+        assert_eq!(None, Options::from_args(&["--opponent", "genius"]));
+        assert_eq!(None, Options::from_args(&["--fly", "away"]));
+        assert_eq!(
+            None,
+            Options::from_args(&["--variant", "ultimate", "--size", "5"])
+        );
+        assert_eq!(
+            None,
+            Options::from_args(&["--size", "4", "--in-a-row", "1"])
+        );
+        assert_eq!(
+            None,
+            Options::from_args(&["--size", "4", "--in-a-row", "5"])
+        );
+        assert_eq!(None, Options::from_args(&["--in-a-row", "4"]));
+        assert_eq!(None, Options::from_args(&["--best-of", "0"]));
+        assert_eq!(None, Options::from_args(&["--best-of", "3", "--no-replay"]));
+        assert_eq!(
+            None,
+            Options::from_args(&["--best-of", "3", "--host", "4000"])
+        );
+        assert_eq!(
+            None,
+            Options::from_args(&["--join", "localhost:4000", "--best-of", "3"])
+        );
+    }
+
+    #[test]
+    fn profiles_name_the_players() {
+        let options =
+            Options::from_args(&["--profile-o", " Alice ", "--profile-x", "Bob"]).unwrap();
+        assert_eq!(Some("Alice".to_owned()), options.profile_o);
+        assert_eq!(Some("Bob".to_owned()), options.profile_x);
+        assert_eq!("profiles.txt", options.profiles_path());
+        let options = Options::from_args(&["--profiles", "ladder.txt"]).unwrap();
+        assert_eq!("ladder.txt", options.profiles_path());
+        assert_eq!(None, Options::from_args(&["--profile-o", " "]));
+        assert_eq!(
+            None,
+            Options::from_args(&["--profile-o", "Alice", "--profile-x", " Alice"])
+        );
+    }
+
+    #[test]
+    fn best_of_sets_the_match_length() {
+        assert_eq!(
+            Some(5),
+            Options::from_args(&["--best-of", "5"]).unwrap().best_of
+        );
+    }
+}
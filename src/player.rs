@@ -12,11 +12,16 @@ impl std::fmt::Display for Player {
 }
 
 impl Player {
-    /// Swaps the active player
-    pub(crate) fn swap(&mut self) {
-        *self = match self {
+    /// Gets the other player.
+    pub const fn opponent(self) -> Self {
+        match self {
             Self::O => Self::X,
             Self::X => Self::O,
         }
     }
+
+    /// Swaps the active player
+    pub(crate) fn swap(&mut self) {
+        *self = self.opponent();
+    }
 }
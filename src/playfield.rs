@@ -0,0 +1,298 @@
+//! Provides functionality for the [Playfield] enum
+//!
+//! Moves on a [Playfield] are cell indexes. On a [Grid] they are the grid's own
+//! indexes. In [Ultimate] the index is `9 * sub-board + cell`, where both are
+//! positions in [`Cell::ALL`].
+
+use crate::bit_patterns::Cell;
+use crate::{
+    Board, Difficulty, Error, GameRecord, Grid, Outcome, Player, Playfield, Rating, Rng, Ultimate,
+};
+
+/// Splits an [Ultimate] move index into its sub-board and cell.
+fn split_index(index: usize) -> Option<(Cell, Cell)> {
+    Some((*Cell::ALL.get(index / 9)?, *Cell::ALL.get(index % 9)?))
+}
+
+/// Joins a sub-board and cell into an [Ultimate] move index.
+fn join_index(board: Cell, cell: Cell) -> usize {
+    9 * (board.number() as usize - 1) + cell.number() as usize - 1
+}
+
+/// Parses a single digit into a [Cell].
+fn parse_digit(digit: char) -> Option<Cell> {
+    let number = digit.to_digit(10)? as usize;
+    Cell::ALL.get(number.checked_sub(1)?).copied()
+}
+
+impl Playfield {
+    /// Creates an empty playfield of the same kind.
+    pub(crate) fn cleared(&self) -> Self {
+        match self {
+            Self::Grid(grid) => Self::Grid(grid.cleared()),
+            Self::Misere(grid) => Self::Misere(grid.cleared()),
+            Self::Ultimate(_) => Self::Ultimate(Ultimate::new()),
+        }
+    }
+
+    /// Names the kind of playfield, such as `3x3`, `5x5 4` for four in a row, `Ultimate` or `Misere`.
+    pub(crate) fn name(&self) -> String {
+        match self {
+            Self::Grid(grid) if grid.as_board().is_some() => "3x3".to_owned(),
+            Self::Grid(grid) => format!("{size}x{size} {}", grid.in_a_row(), size = grid.size()),
+            Self::Ultimate(_) => "Ultimate".to_owned(),
+            Self::Misere(_) => "Misere".to_owned(),
+        }
+    }
+
+    /// Creates an empty playfield from its name.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let mut words = name.split_whitespace();
+        match (words.next(), words.next(), words.next()) {
+            (Some(board), in_a_row, None) => GameRecord::parse_playfield(board, in_a_row).ok(),
+            _ => None,
+        }
+    }
+
+    /// Gets the message asking a player for their move.
+    pub(crate) fn prompt(&self) -> String {
+        match self {
+            Self::Grid(grid) | Self::Misere(grid) => {
+                format!("Enter a value between 1-{}", grid.cell_count())
+            }
+            Self::Ultimate(ultimate) => match ultimate.forced_board() {
+                Some(board) => format!("Enter a value between 1-9 for sub-board {}", board),
+                None => "Enter a sub-board then a cell, such as 53 for the centre board's bottom-right cell"
+                    .to_owned(),
+            },
+        }
+    }
+
+    /// Parses a move, as typed by a player, into a cell index.
+    pub(crate) fn parse_cell(&self, input: &str) -> Result<usize, Error> {
+        match self {
+            Self::Grid(grid) | Self::Misere(grid) => grid.parse_cell(input),
+            Self::Ultimate(ultimate) => {
+                let invalid = || Error::InvalidCell(input.to_owned());
+                let digits: Vec<Cell> = input
+                    .chars()
+                    .map(parse_digit)
+                    .collect::<Option<_>>()
+                    .ok_or_else(invalid)?;
+                match (digits.as_slice(), ultimate.forced_board()) {
+                    ([cell], Some(board)) => Ok(join_index(board, *cell)),
+                    ([board, cell], _) => Ok(join_index(*board, *cell)),
+                    _ => Err(invalid()),
+                }
+            }
+        }
+    }
+
+    /// Gets the player who set the cell at the given index, if any.
+    pub(crate) fn owner(&self, index: usize) -> Option<Player> {
+        match self {
+            Self::Grid(grid) | Self::Misere(grid) => grid.owner(index),
+            Self::Ultimate(ultimate) => {
+                split_index(index).and_then(|(board, cell)| ultimate.board(board).owner(cell))
+            }
+        }
+    }
+
+    /// Sets the cell at the given index for a given player, if the move is legal.
+    pub(crate) fn apply_move(&mut self, player: Player, index: usize) -> Result<(), Error> {
+        match self {
+            Self::Grid(grid) | Self::Misere(grid) => grid.apply_move(player, index),
+            Self::Ultimate(ultimate) => {
+                let (board, cell) = split_index(index)
+                    .ok_or_else(|| Error::InvalidCell((index + 1).to_string()))?;
+                ultimate.apply_move(player, board, cell)
+            }
+        }
+    }
+
+    /// Gets the [Outcome] of the game as it currently stands.
+    pub(crate) fn outcome(&self) -> Outcome {
+        match self {
+            Self::Grid(grid) => grid.outcome(),
+            Self::Ultimate(ultimate) => ultimate.outcome(),
+            Self::Misere(grid) => match grid.outcome() {
+                Outcome::Win(player) => Outcome::Win(player.opponent()),
+                outcome => outcome,
+            },
+        }
+    }
+
+    /// Picks a move for the given player at the given [Difficulty].
+    pub(crate) fn computer_move(
+        &self,
+        player: Player,
+        difficulty: Difficulty,
+        rng: &mut Rng,
+    ) -> Option<usize> {
+        match self {
+            Self::Grid(grid) => grid.computer_move(player, difficulty, rng),
+            Self::Ultimate(ultimate) => ultimate
+                .computer_move(player, difficulty, rng)
+                .map(|(board, cell)| join_index(board, cell)),
+            Self::Misere(grid) => grid
+                .as_board()
+                .and_then(|board| board.misere_move(player, difficulty, rng))
+                .map(|cell| cell.number() as usize - 1),
+        }
+    }
+
+    /// Checks whether moves can be rated on this board, without the search that rating them takes.
+    pub(crate) fn supports_hints(&self) -> bool {
+        match self {
+            Self::Grid(grid) | Self::Misere(grid) => grid.as_board().is_some(),
+            Self::Ultimate(_) => false,
+        }
+    }
+
+    /// Rates every legal move for the given player, which can only be done on the classic board.
+    pub(crate) fn rate_moves(&self, player: Player) -> Result<Vec<(usize, Rating)>, Error> {
+        match self {
+            Self::Grid(grid) => grid
+                .as_board()
+                .map(|board| {
+                    board
+                        .rate_moves(player)
+                        .into_iter()
+                        .map(|(cell, rating)| (cell.number() as usize - 1, rating))
+                        .collect()
+                })
+                .ok_or(Error::NoHints),
+            Self::Ultimate(_) => Err(Error::NoHints),
+            Self::Misere(grid) => grid
+                .as_board()
+                .map(|board| {
+                    board
+                        .rate_misere_moves(player)
+                        .into_iter()
+                        .map(|(cell, rating)| (cell.number() as usize - 1, rating))
+                        .collect()
+                })
+                .ok_or(Error::NoHints),
+        }
+    }
+
+    /// Notes the moves that win straight away, block the opponent or make a fork, on the classic board.
+    ///
+    /// In Misère only the moves that complete a line, and so lose, are noted.
+    pub(crate) fn tactics(&self, player: Player) -> Vec<(usize, &'static str)> {
+        let notes = |cells: Vec<Cell>, note| {
+            cells
+                .into_iter()
+                .map(move |cell| (cell.number() as usize - 1, note))
+        };
+        let board = match self {
+            Self::Grid(grid) => grid.as_board(),
+            Self::Ultimate(_) => None,
+            Self::Misere(grid) => {
+                return grid
+                    .as_board()
+                    .map(|board| notes(board.winning_moves(player), "loses now").collect())
+                    .unwrap_or_default();
+            }
+        };
+        let Some(board) = board else {
+            return Vec::new();
+        };
+        notes(board.winning_moves(player), "wins now")
+            .chain(notes(board.blocking_moves(player), "blocks a win"))
+            .chain(notes(board.fork_moves(player), "makes a fork"))
+            .collect()
+    }
+
+    /// Lists the index of every cell in a completed line.
+    #[cfg(feature = "tui")]
+    pub(crate) fn winning_line(&self) -> Vec<usize> {
+        match self {
+            Self::Grid(grid) | Self::Misere(grid) => grid.winning_line(),
+            Self::Ultimate(ultimate) => ultimate
+                .winning_line()
+                .into_iter()
+                .map(|(board, cell)| join_index(board, cell))
+                .collect(),
+        }
+    }
+
+    /// Gets the number of rows and columns of cells on screen.
+    #[cfg(feature = "tui")]
+    pub(crate) fn dimension(&self) -> usize {
+        match self {
+            Self::Grid(grid) | Self::Misere(grid) => grid.size(),
+            Self::Ultimate(_) => 9,
+        }
+    }
+
+    /// Gets the index of the cell at a row and column, counting rows from the top.
+    #[cfg(feature = "tui")]
+    pub(crate) fn index_at(&self, row: usize, column: usize) -> usize {
+        match self {
+            Self::Grid(grid) | Self::Misere(grid) => (grid.size() - 1 - row) * grid.size() + column,
+            Self::Ultimate(_) => {
+                let board = (2 - row / 3) * 3 + column / 3;
+                let cell = (2 - row % 3) * 3 + column % 3;
+                9 * board + cell
+            }
+        }
+    }
+
+    /// Describes every completed line, and who completed it.
+    pub(crate) fn winning_lines(&self) -> Vec<(Player, String)> {
+        match self {
+            Self::Grid(grid) | Self::Misere(grid) if grid.as_board().is_some() => grid
+                .as_board()
+                .map(Board::winning_patterns)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(player, pattern)| (player, format!("the {}", pattern.name())))
+                .collect(),
+            Self::Grid(grid) | Self::Misere(grid) => grid
+                .winning_lines()
+                .into_iter()
+                .map(|(player, line)| {
+                    let mut names: Vec<String> = line.iter().map(|i| self.cell_name(*i)).collect();
+                    let last = names.pop().unwrap_or_default();
+                    (
+                        player,
+                        format!("the line through {} and {}", names.join(", "), last),
+                    )
+                })
+                .collect(),
+            Self::Ultimate(ultimate) => ultimate
+                .winning_patterns()
+                .into_iter()
+                .map(|(player, pattern)| (player, format!("the {} of sub-boards", pattern.name())))
+                .collect(),
+        }
+    }
+
+    /// Formats a cell index the way a player would type it.
+    pub(crate) fn cell_name(&self, index: usize) -> String {
+        match self {
+            Self::Grid(_) | Self::Misere(_) => (index + 1).to_string(),
+            Self::Ultimate(_) => split_index(index)
+                .map(|(board, cell)| format!("{}{}", board, cell))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl std::fmt::Display for Playfield {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Grid(grid) | Self::Misere(grid) if f.alternate() => write!(f, "{:#}", grid),
+            Self::Grid(grid) | Self::Misere(grid) => write!(f, "{}", grid),
+            Self::Ultimate(ultimate) if f.alternate() => write!(f, "{:#}", ultimate),
+            Self::Ultimate(ultimate) => write!(f, "{}", ultimate),
+        }
+    }
+}
+
+impl From<Grid> for Playfield {
+    fn from(grid: Grid) -> Self {
+        Self::Grid(grid)
+    }
+}
@@ -0,0 +1,101 @@
+//! Provides functionality for the [PositionCounts] struct
+//!
+//! Positions are found by walking the game tree from the empty [Board],
+//! one move number at a time, so each position is only expanded once.
+
+use std::collections::HashSet;
+
+use crate::bit_patterns::Cell;
+use crate::{Board, Player, PositionCounts};
+
+// Private block
+impl Board {
+    /// Counts how many cells have been set.
+    const fn move_number(&self) -> usize {
+        (self.cells & 0b10_1010_1010_1010_1010).count_ones() as usize
+    }
+}
+
+// Public block
+impl Board {
+    /// Lists every position that can be reached in a game where X moves first,
+    /// in order of move number.
+    pub fn reachable_positions() -> Vec<Self> {
+        let mut positions = vec![Self::new()];
+        let mut layer = vec![Self::new()];
+        let mut player = Player::X;
+        while !layer.is_empty() {
+            let mut seen = HashSet::new();
+            let mut next_layer = Vec::new();
+            for board in layer {
+                if board.has_player_won() || board.is_every_cell_set() {
+                    continue;
+                }
+                for cell in Cell::ALL {
+                    if board.owner(cell).is_some() {
+                        continue;
+                    }
+                    let mut next = board;
+                    next.set_cell(player, cell);
+                    if seen.insert(next) {
+                        next_layer.push(next);
+                    }
+                }
+            }
+            positions.extend_from_slice(&next_layer);
+            layer = next_layer;
+            player.swap();
+        }
+        positions
+    }
+}
+
+// Public block
+impl PositionCounts {
+    /// Counts every position that can be reached in a game where X moves first.
+    pub fn new() -> Self {
+        let positions = Board::reachable_positions();
+        let mut counts = Self {
+            total: positions.len(),
+            canonical: positions
+                .iter()
+                .map(|board| board.canonical().0)
+                .collect::<HashSet<Board>>()
+                .len(),
+            ..Self::default()
+        };
+        for board in &positions {
+            let move_number = board.move_number();
+            counts.by_move[move_number] += 1;
+            match board.winner() {
+                Some(Player::X) => counts.x_wins += 1,
+                Some(Player::O) => counts.o_wins += 1,
+                None if board.is_every_cell_set() => counts.draws += 1,
+                None if move_number % 2 == 0 => counts.x_to_move += 1,
+                None => counts.o_to_move += 1,
+            }
+        }
+        counts
+    }
+}
+
+impl std::fmt::Display for PositionCounts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Reachable positions: {}", self.total)?;
+        writeln!(f, "Up to symmetry: {}", self.canonical)?;
+        writeln!(f)?;
+        writeln!(f, "Cells set | Positions")?;
+        for (move_number, count) in self.by_move.iter().enumerate() {
+            writeln!(f, "{:>9} | {:>9}", move_number, count)?;
+        }
+        writeln!(f)?;
+        writeln!(f, "Won by X: {}", self.x_wins)?;
+        writeln!(f, "Won by O: {}", self.o_wins)?;
+        writeln!(f, "Drawn: {}", self.draws)?;
+        writeln!(f, "X to move: {}", self.x_to_move)?;
+        writeln!(f, "O to move: {}", self.o_to_move)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod position_tests {
+    use crate::{Board, PositionCounts};
+
+    #[test]
+    fn every_reachable_position_is_counted() {
+        let counts = PositionCounts::new();
+        assert_eq!(5478, counts.total);
+        assert_eq!(765, counts.canonical);
+        assert_eq!(
+            [1, 9, 72, 252, 756, 1260, 1520, 1140, 390, 78],
+            counts.by_move
+        );
+    }
+
+    #[test]
+    fn positions_are_counted_by_result_and_side_to_move() {
+        let counts = PositionCounts::new();
+        assert_eq!(626, counts.x_wins);
+        assert_eq!(316, counts.o_wins);
+        assert_eq!(16, counts.draws);
+        assert_eq!(
+            counts.total,
+            counts.x_wins + counts.o_wins + counts.draws + counts.x_to_move + counts.o_to_move
+        );
+    }
+
+    #[test]
+    fn positions_are_listed_once_in_move_order() {
+        let positions = Board::reachable_positions();
+        assert_eq!(Board::new(), positions[0]);
+        let mut unique = positions.clone();
+        unique.sort_by_key(|board| board.cells);
+        unique.dedup();
+        assert_eq!(positions.len(), unique.len());
+        assert!(positions
+            .windows(2)
+            .all(|pair| pair[0].move_number() <= pair[1].move_number()));
+    }
+
+    #[test]
+    fn counts_are_displayed_as_a_table() {
+        let report = PositionCounts::new().to_string();
+        assert!(report.starts_with("Reachable positions: 5478\nUp to symmetry: 765\n"));
+        assert!(report.contains("        9 |        78\n"));
+        assert!(report.ends_with("Drawn: 16\nX to move: 2423\nO to move: 2097\n"));
+    }
+}
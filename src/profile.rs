@@ -0,0 +1,302 @@
+//! Provides functionality for the [Profile] struct
+//!
+//! Profiles are kept in a text file, one block of headers per profile,
+//! in the same style as a game record:
+//!
+//! ```text
+//! [Profile "Alice"]
+//! [Rating "1532"]
+//! [Wins "7"]
+//! [Draws "3"]
+//! [Losses "2"]
+//! [Moves "80"]
+//! [Openings "1:4 5:8"]
+//! [Streak "Win 3"]
+//! [LongestWinStreak "4"]
+//! [LongestLossStreak "1"]
+//! ```
+//!
+//! Openings list each cell a player has started with and how often.
+//! Headers that are not understood are skipped.
+
+use std::collections::BTreeMap;
+use std::io;
+
+use crate::elo::INITIAL_RATING;
+use crate::record::parse_header;
+use crate::{Error, GameResult, Outcome, Player, Profile, Profiles};
+
+/// Formats a [GameResult], as written in a profiles file.
+const fn result_name(result: GameResult) -> &'static str {
+    match result {
+        GameResult::Win => "Win",
+        GameResult::Draw => "Draw",
+        GameResult::Loss => "Loss",
+    }
+}
+
+/// Parses a [GameResult], as written in a profiles file.
+fn parse_result(name: &str) -> Result<GameResult, String> {
+    match name {
+        "Win" => Ok(GameResult::Win),
+        "Draw" => Ok(GameResult::Draw),
+        "Loss" => Ok(GameResult::Loss),
+        _ => Err(format!("{} is not a game result", name)),
+    }
+}
+
+/// Parses a count, as written in a profiles file.
+fn parse_count(value: &str) -> Result<usize, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} is not a valid count", value))
+}
+
+/// Describes a number of games that all ended the same way, such as `3 wins`.
+fn describe_games(result: GameResult, count: usize) -> String {
+    let (one, many) = match result {
+        GameResult::Win => ("win", "wins"),
+        GameResult::Draw => ("draw", "draws"),
+        GameResult::Loss => ("loss", "losses"),
+    };
+    format!("{} {}", count, if count == 1 { one } else { many })
+}
+
+// Public-Crate block
+impl GameResult {
+    /// Gets how a finished game went for the given player.
+    pub(crate) fn of(outcome: Outcome, player: Player) -> Self {
+        match outcome {
+            Outcome::Win(winner) if winner == player => Self::Win,
+            Outcome::Win(_) => Self::Loss,
+            Outcome::Draw | Outcome::InProgress => Self::Draw,
+        }
+    }
+}
+
+// Private block
+impl Profile {
+    /// Fills in the field named by a header.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "Wins" => self.wins = parse_count(value)?,
+            "Draws" => self.draws = parse_count(value)?,
+            "Losses" => self.losses = parse_count(value)?,
+            "Moves" => self.moves = parse_count(value)?,
+            "Openings" => {
+                for opening in value.split_whitespace() {
+                    let (cell, count) = opening
+                        .split_once(':')
+                        .ok_or_else(|| format!("{} is not a valid opening", opening))?;
+                    self.openings.insert(cell.to_owned(), parse_count(count)?);
+                }
+            }
+            "Streak" => {
+                let (result, count) = value
+                    .split_once(' ')
+                    .ok_or_else(|| format!("{} is not a valid streak", value))?;
+                self.streak = Some((parse_result(result)?, parse_count(count)?));
+            }
+            "LongestWinStreak" => self.longest_win_streak = parse_count(value)?,
+            "LongestLossStreak" => self.longest_loss_streak = parse_count(value)?,
+            "Rating" => {
+                self.rating = value
+                    .parse()
+                    .map_err(|_| format!("{} is not a valid rating", value))?
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    /// Writes the profile's headers, as kept in a profiles file.
+    fn write_headers(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "[Profile \"{}\"]", self.name)?;
+        writeln!(f, "[Rating \"{}\"]", self.rating)?;
+        writeln!(f, "[Wins \"{}\"]", self.wins)?;
+        writeln!(f, "[Draws \"{}\"]", self.draws)?;
+        writeln!(f, "[Losses \"{}\"]", self.losses)?;
+        writeln!(f, "[Moves \"{}\"]", self.moves)?;
+        let openings: Vec<String> = self
+            .openings
+            .iter()
+            .map(|(cell, count)| format!("{}:{}", cell, count))
+            .collect();
+        writeln!(f, "[Openings \"{}\"]", openings.join(" "))?;
+        if let Some((result, count)) = self.streak {
+            writeln!(f, "[Streak \"{} {}\"]", result_name(result), count)?;
+        }
+        writeln!(f, "[LongestWinStreak \"{}\"]", self.longest_win_streak)?;
+        writeln!(f, "[LongestLossStreak \"{}\"]", self.longest_loss_streak)
+    }
+}
+
+// Public block
+impl Profile {
+    /// Creates a profile that has not played any games, at the starting rating.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            ..Self::default()
+        }
+    }
+
+    /// Counts every game the player has finished.
+    pub const fn games(&self) -> usize {
+        self.wins + self.draws + self.losses
+    }
+
+    /// Gets the average number of moves in the player's games, or [None] before their first game.
+    pub fn average_length(&self) -> Option<f64> {
+        match self.games() {
+            0 => None,
+            games => Some(self.moves as f64 / games as f64),
+        }
+    }
+
+    /// Gets the cell the player most often starts with, preferring the first named on a tie.
+    pub fn favourite_opening(&self) -> Option<&str> {
+        self.openings
+            .iter()
+            .fold(
+                None,
+                |best: Option<(&String, usize)>, (cell, count)| match best {
+                    Some((_, most)) if most >= *count => best,
+                    _ => Some((cell, *count)),
+                },
+            )
+            .map(|(cell, _)| cell.as_str())
+    }
+
+    /// Adds a finished game, given its length and the cell the player started with, if they moved at all.
+    pub fn record(&mut self, result: GameResult, moves: usize, opening: Option<String>) {
+        match result {
+            GameResult::Win => self.wins += 1,
+            GameResult::Draw => self.draws += 1,
+            GameResult::Loss => self.losses += 1,
+        }
+        self.moves += moves;
+        if let Some(cell) = opening {
+            *self.openings.entry(cell).or_insert(0) += 1;
+        }
+        let count = match self.streak {
+            Some((last, count)) if last == result => count + 1,
+            _ => 1,
+        };
+        self.streak = Some((result, count));
+        match result {
+            GameResult::Win => self.longest_win_streak = self.longest_win_streak.max(count),
+            GameResult::Loss => self.longest_loss_streak = self.longest_loss_streak.max(count),
+            GameResult::Draw => (),
+        }
+    }
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            rating: INITIAL_RATING,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+            moves: 0,
+            openings: BTreeMap::new(),
+            streak: None,
+            longest_win_streak: 0,
+            longest_loss_streak: 0,
+        }
+    }
+}
+
+impl std::fmt::Display for Profile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.name)?;
+        writeln!(f, "  Rating: {}", self.rating)?;
+        writeln!(
+            f,
+            "  Games: {} ({} won, {} drawn, {} lost)",
+            self.games(),
+            self.wins,
+            self.draws,
+            self.losses
+        )?;
+        if let Some(average) = self.average_length() {
+            writeln!(f, "  Average game length: {:.1} moves", average)?;
+        }
+        if let Some(cell) = self.favourite_opening() {
+            writeln!(f, "  Favourite opening cell: {}", cell)?;
+        }
+        if let Some((result, count)) = self.streak {
+            writeln!(f, "  Current streak: {}", describe_games(result, count))?;
+        }
+        writeln!(f, "  Longest winning streak: {}", self.longest_win_streak)?;
+        writeln!(f, "  Longest losing streak: {}", self.longest_loss_streak)
+    }
+}
+
+// Public-Crate block
+impl Profiles {
+    /// Parses every profile in a profiles file.
+    pub(crate) fn parse(text: &str) -> Result<Self, String> {
+        let mut profiles: Vec<Profile> = Vec::new();
+        for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let (key, value) = parse_header(line)?;
+            if key == "Profile" {
+                profiles.push(Profile::new(value));
+                continue;
+            }
+            profiles
+                .last_mut()
+                .ok_or_else(|| format!("{} comes before the first profile", line))?
+                .set(key, value)?;
+        }
+        Ok(Self { profiles })
+    }
+
+    /// Reads the profiles kept in the given file, which has none if it does not exist yet.
+    pub(crate) fn load(path: &str) -> Result<Self, Error> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|e| Error::InvalidProfiles(path.to_owned(), e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::Load(path.to_owned(), e)),
+        }
+    }
+
+    /// Writes every profile to the given file.
+    pub(crate) fn save(&self, path: &str) -> Result<(), Error> {
+        std::fs::write(path, self.to_string()).map_err(|e| Error::Save(path.to_owned(), e))
+    }
+
+    /// Gets every profile, in the order they were first saved.
+    pub(crate) fn profiles(&self) -> &[Profile] {
+        &self.profiles
+    }
+
+    /// Gets the profile with the given name, creating it if there is none.
+    pub(crate) fn profile_mut(&mut self, name: &str) -> &mut Profile {
+        let index = match self.profiles.iter().position(|p| p.name == name) {
+            Some(index) => index,
+            None => {
+                self.profiles.push(Profile::new(name));
+                self.profiles.len() - 1
+            }
+        };
+        &mut self.profiles[index]
+    }
+}
+
+impl std::fmt::Display for Profiles {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, profile) in self.profiles.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            profile.write_headers(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
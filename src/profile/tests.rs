@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod profile_tests {
+    use crate::{GameResult, Profile, Profiles};
+
+    const PROFILES: &str = "[Profile \"Alice\"]
+[Rating \"1500\"]
+[Wins \"2\"]
+[Draws \"1\"]
+[Losses \"0\"]
+[Moves \"19\"]
+[Openings \"1:1 5:2\"]
+[Streak \"Win 2\"]
+[LongestWinStreak \"2\"]
+[LongestLossStreak \"0\"]
+
+[Profile \"Bob\"]
+[Rating \"1500\"]
+[Wins \"0\"]
+[Draws \"0\"]
+[Losses \"0\"]
+[Moves \"0\"]
+[Openings \"\"]
+[LongestWinStreak \"0\"]
+[LongestLossStreak \"0\"]
+";
+
+    fn alice() -> Profile {
+        let mut alice = Profile::new("Alice");
+        alice.record(GameResult::Draw, 9, Some("1".to_owned()));
+        alice.record(GameResult::Win, 5, Some("5".to_owned()));
+        alice.record(GameResult::Win, 5, Some("5".to_owned()));
+        alice
+    }
+
+    #[test]
+    fn results_add_up() {
+        let alice = alice();
+        assert_eq!(3, alice.games());
+        assert_eq!((2, 1, 0), (alice.wins, alice.draws, alice.losses));
+        assert_eq!(Some(19.0 / 3.0), alice.average_length());
+        assert_eq!(Some("5"), alice.favourite_opening());
+    }
+
+    #[test]
+    fn streaks_follow_the_latest_results() {
+        let mut alice = alice();
+        assert_eq!(Some((GameResult::Win, 2)), alice.streak);
+        alice.record(GameResult::Loss, 6, None);
+        alice.record(GameResult::Loss, 6, None);
+        alice.record(GameResult::Win, 7, None);
+        assert_eq!(Some((GameResult::Win, 1)), alice.streak);
+        assert_eq!(2, alice.longest_win_streak);
+        assert_eq!(2, alice.longest_loss_streak);
+    }
+
+    #[test]
+    fn new_profiles_have_no_statistics() {
+        let bob = Profile::new("Bob");
+        assert_eq!(1500, bob.rating);
+        assert_eq!(Profile::default().rating, bob.rating);
+        assert_eq!(0, bob.games());
+        assert_eq!(None, bob.average_length());
+        assert_eq!(None, bob.favourite_opening());
+        assert_eq!(None, bob.streak);
+    }
+
+    #[test]
+    fn ties_prefer_the_first_opening() {
+        let mut profile = Profile::new("Cy");
+        profile.record(GameResult::Draw, 9, Some("7".to_owned()));
+        profile.record(GameResult::Draw, 9, Some("3".to_owned()));
+        assert_eq!(Some("3"), profile.favourite_opening());
+    }
+
+    #[test]
+    fn profiles_file_round_trips() {
+        let mut profiles = Profiles::default();
+        *profiles.profile_mut("Alice") = alice();
+        profiles.profile_mut("Bob");
+        assert_eq!(PROFILES, profiles.to_string());
+        assert_eq!(profiles, Profiles::parse(PROFILES).unwrap());
+    }
+
+    #[test]
+    fn profile_mut_finds_existing_profiles() {
+        let mut profiles = Profiles::parse(PROFILES).unwrap();
+        profiles
+            .profile_mut("Alice")
+            .record(GameResult::Loss, 8, None);
+        assert_eq!(2, profiles.profiles().len());
+        assert_eq!(4, profiles.profiles()[0].games());
+    }
+
+    #[test]
+    fn invalid_profiles_are_rejected() {
+        assert!(Profiles::parse("[Wins \"2\"]").is_err());
+        assert!(Profiles::parse("[Profile \"Alice\"]\n[Wins \"two\"]").is_err());
+        assert!(Profiles::parse("[Profile \"Alice\"]\n[Streak \"Tie 2\"]").is_err());
+        assert!(Profiles::parse("[Profile \"Alice\"]\nWins 2").is_err());
+        assert!(Profiles::parse("[Profile \"Alice\"]\n[Rating \"high\"]").is_err());
+        assert!(Profiles::parse("[Profile \"Alice\"]\n[Rank \"1\"]").is_ok());
+    }
+
+    #[test]
+    fn summary_describes_the_profile() {
+        assert_eq!(
+            "Alice\n  Rating: 1500\n  Games: 3 (2 won, 1 drawn, 0 lost)\n  Average game length: 6.3 moves\n  \
+             Favourite opening cell: 5\n  Current streak: 2 wins\n  Longest winning streak: 2\n  \
+             Longest losing streak: 0\n",
+            alice().to_string()
+        );
+    }
+}
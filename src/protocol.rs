@@ -0,0 +1,200 @@
+//! Provides the engine protocol and functionality for the [EngineProcess] struct
+//!
+//! An engine is a program that plays by reading commands on its standard input and answering
+//! on its standard output, one line at a time:
+//!
+//! - `newgame` starts a classic game. A board can follow, such as `newgame 5x5 4` for four in a
+//!   row on a 5x5 board, `newgame Ultimate`, or `newgame Misere` where completing a line loses.
+//! - `position 5 1 9` sets up the position after the given moves, numbered as players type them.
+//!   The moves start with Player X unless the first word is `O`, such as `position O 5 1`.
+//! - `go` asks for a move, answered with `bestmove 3`.
+//! - `isready` is answered with `readyok` once the engine has caught up.
+//! - `quit` stops the engine.
+//!
+//! Anything the engine cannot do is answered with `error` and the reason.
+
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::record::parse_player;
+use crate::{Difficulty, EngineProcess, Error, Grid, Player, Playfield, Rng};
+
+/// How long an engine is given to stop once told to quit, before it is killed.
+const QUIT_GRACE: Duration = Duration::from_secs(1);
+
+/// The position an engine has been asked to play from.
+struct Position {
+    playfield: Playfield,
+    to_move: Player,
+}
+
+impl Position {
+    /// Sets up the position after the given moves, which start with `start`.
+    fn after(playfield: &Playfield, start: Player, moves: &[&str]) -> Result<Self, Error> {
+        let mut position = Self {
+            playfield: playfield.cleared(),
+            to_move: start,
+        };
+        for name in moves {
+            let cell = position.playfield.parse_cell(name)?;
+            position.playfield.apply_move(position.to_move, cell)?;
+            position.to_move.swap();
+        }
+        Ok(position)
+    }
+}
+
+/// Answers engine protocol commands from `reader` until told to quit or the input ends.
+pub(crate) fn serve<R: BufRead, W: Write>(
+    reader: R,
+    mut writer: W,
+    difficulty: Difficulty,
+) -> Result<(), Error> {
+    let mut rng = Rng::from_time();
+    let mut position = Position::after(&Playfield::from(Grid::classic()), Player::X, &[])?;
+    for line in reader.lines() {
+        let line = line?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let reply = match words.as_slice() {
+            [] => continue,
+            ["quit"] => return Ok(()),
+            ["isready"] => Ok("readyok".to_owned()),
+            ["newgame", board @ ..] => {
+                let playfield = match board {
+                    [] => Some(Grid::classic().into()),
+                    _ => Playfield::from_name(&board.join(" ")),
+                };
+                match playfield {
+                    Some(playfield) => {
+                        position = Position::after(&playfield, Player::X, &[])?;
+                        continue;
+                    }
+                    None => Err(Error::Protocol(line.clone())),
+                }
+            }
+            ["position", moves @ ..] => {
+                let (start, moves) = match moves.split_first() {
+                    Some((first, rest)) => match parse_player(first) {
+                        Ok(start) => (start, rest),
+                        Err(_) => (Player::X, moves),
+                    },
+                    None => (Player::X, moves),
+                };
+                match Position::after(&position.playfield, start, moves) {
+                    Ok(next) => {
+                        position = next;
+                        continue;
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+            ["go"] => position
+                .playfield
+                .computer_move(position.to_move, difficulty, &mut rng)
+                .map(|cell| format!("bestmove {}", position.playfield.cell_name(cell)))
+                .ok_or(Error::GameOver),
+            _ => Err(Error::Protocol(line.clone())),
+        };
+        match reply {
+            Ok(reply) => writeln!(writer, "{}", reply)?,
+            Err(e) => writeln!(writer, "error {}", e)?,
+        }
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+// Private block
+impl EngineProcess {
+    /// Sends a command to the engine.
+    fn send(&mut self, command: &str) -> Result<(), Error> {
+        let writer = self.writer.as_mut().ok_or(Error::EngineExited)?;
+        writeln!(writer, "{}", command).map_err(|_| Error::EngineExited)?;
+        writer.flush().map_err(|_| Error::EngineExited)
+    }
+
+    /// Waits for the next line from the engine.
+    fn receive(&mut self) -> Result<String, Error> {
+        let mut buffer = String::new();
+        if self.reader.read_line(&mut buffer)? == 0 {
+            return Err(Error::EngineExited);
+        }
+        Ok(buffer.trim().to_owned())
+    }
+}
+
+// Public-Crate block
+impl EngineProcess {
+    /// Starts the engine given by a command line, such as `python3 bot.py`.
+    ///
+    /// The command line is split on whitespace, without any quoting.
+    pub(crate) fn spawn(command_line: &str) -> Result<Self, Error> {
+        let mut words = command_line.split_whitespace();
+        let program = words
+            .next()
+            .ok_or_else(|| Error::Protocol(command_line.to_owned()))?;
+        let mut child = Command::new(program)
+            .args(words)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()?;
+        let writer = child.stdin.take().ok_or(Error::EngineExited)?;
+        let reader = child.stdout.take().ok_or(Error::EngineExited)?;
+        Ok(Self {
+            child,
+            reader: BufReader::new(reader),
+            writer: Some(writer),
+        })
+    }
+
+    /// Tells the engine a new game is starting on the given kind of playfield.
+    pub(crate) fn new_game(&mut self, playfield: &Playfield) -> Result<(), Error> {
+        match playfield.name().as_str() {
+            "3x3" => self.send("newgame"),
+            name => self.send(&format!("newgame {}", name)),
+        }
+    }
+
+    /// Asks the engine for its move after the given moves, returning the move as a player would type it.
+    pub(crate) fn best_move(&mut self, start: Player, moves: &[String]) -> Result<String, Error> {
+        let start = match start {
+            Player::X => String::new(),
+            Player::O => "O ".to_owned(),
+        };
+        self.send(&format!("position {}{}", start, moves.join(" ")))?;
+        self.send("go")?;
+        loop {
+            let line = self.receive()?;
+            if let Some(cell) = line.strip_prefix("bestmove ") {
+                return Ok(cell.trim().to_owned());
+            }
+            if line.starts_with("error") {
+                return Err(Error::Protocol(line));
+            }
+        }
+    }
+}
+
+impl Drop for EngineProcess {
+    fn drop(&mut self) {
+        // The engine may already have stopped, in which case there is nothing to clean up.
+        let _ = self.send("quit");
+        // Closing its input lets an engine that ignores `quit` see the end of its commands,
+        // and one that still has not stopped is killed rather than waited on forever.
+        self.writer = None;
+        let deadline = Instant::now() + QUIT_GRACE;
+        while let Ok(None) = self.child.try_wait() {
+            if Instant::now() >= deadline {
+                let _ = self.child.kill();
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        let _ = self.child.wait();
+    }
+}
+
+#[cfg(test)]
+mod tests;
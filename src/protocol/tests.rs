@@ -0,0 +1,160 @@
+#[cfg(test)]
+mod engine_tests {
+    use crate::run_engine_with;
+
+    fn run(input: &str) -> String {
+        let mut output = Vec::new();
+        run_engine_with(input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn engine_takes_the_winning_move() {
+        assert_eq!("bestmove 3\n", run("newgame\nposition 1 4 2 5\ngo\nquit\n"));
+    }
+
+    #[test]
+    fn engine_plays_the_side_to_move() {
+        assert_eq!("bestmove 6\n", run("newgame\nposition O 4 1 5 2\ngo\n"));
+    }
+
+    #[test]
+    fn engine_answers_isready() {
+        assert_eq!("readyok\n", run("isready\nquit\ngo\n"));
+    }
+
+    #[test]
+    fn engine_reports_bad_commands() {
+        let output = run("position 5 5\nnewgame 2x2\nfly\nposition 1 4 2 5 3\ngo\n");
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(
+            vec![
+                "error cell 5 has already been set",
+                "error \"newgame 2x2\" is not understood",
+                "error \"fly\" is not understood",
+                "error the game is already over",
+            ],
+            lines
+        );
+    }
+
+    #[test]
+    fn engine_plays_ultimate() {
+        let output = run("newgame Ultimate\nposition 55\ngo\n");
+        assert!(output.starts_with("bestmove 5"));
+        assert_eq!(12, output.len());
+    }
+
+    #[test]
+    fn engine_avoids_completing_a_line_in_misere() {
+        let output = run("newgame Misere\nposition 7 1 8 2\ngo\n");
+        assert!(output.starts_with("bestmove "));
+        assert_ne!("bestmove 9\n", output);
+    }
+
+    #[test]
+    fn engine_plays_bigger_boards() {
+        let output = run("newgame 4x4 3\nposition 1 16 2 15\ngo\n");
+        assert_eq!("bestmove 3\n", output);
+    }
+}
+
+#[cfg(all(test, unix))]
+mod engine_process_tests {
+    use std::path::PathBuf;
+
+    use crate::{play_against_with, Error};
+
+    /// Writes a shell engine that always answers `go` with the given move,
+    /// returning its command and the script's path.
+    fn stubborn_engine(name: &str, cell: &str) -> (String, PathBuf) {
+        let path = std::env::temp_dir().join(format!(
+            "tic_tac_toe_engine_{}_{}.sh",
+            name,
+            std::process::id()
+        ));
+        let script = format!(
+            "while read c; do case $c in go) echo bestmove {};; quit) exit;; esac; done\n",
+            cell
+        );
+        std::fs::write(&path, script).unwrap();
+        (format!("sh {}", path.display()), path)
+    }
+
+    fn run(command: &str, input: &str) -> (Result<(), Error>, String) {
+        let mut output = Vec::new();
+        let result = play_against_with(command, input.as_bytes(), &mut output);
+        (result, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn engine_process_plays_a_side() {
+        let (command, path) = stubborn_engine("plays", "5");
+        let (result, transcript) = run(&command, "3\nX\nO\n1\n");
+        std::fs::remove_file(path).unwrap();
+        assert!(result.is_ok());
+        assert!(transcript.contains("The engine selects 5\n"));
+        assert!(!transcript.contains("How well should the computer play?"));
+    }
+
+    #[test]
+    fn illegal_engine_move_is_reported() {
+        let (command, path) = stubborn_engine("illegal", "5");
+        let (result, transcript) = run(&command, "3\nX\nO\n1\n2\n");
+        std::fs::remove_file(path).unwrap();
+        assert!(matches!(
+            result,
+            Err(Error::IllegalEngineMove(e)) if matches!(*e, Error::CellOccupied(5))
+        ));
+        assert_eq!(1, transcript.matches("The engine selects").count());
+    }
+
+    #[test]
+    fn engine_keeps_its_side_after_a_load() {
+        let (command, path) = stubborn_engine("load", "3");
+        let record = std::env::temp_dir().join(format!(
+            "tic_tac_toe_engine_load_{}.txt",
+            std::process::id()
+        ));
+        std::fs::write(
+            &record,
+            "[Board \"3x3\"]\n[PlayerO \"Human\"]\n[PlayerX \"Human\"]\n[Date \"2026-10-18\"]\n[Start \"X\"]\n[Result \"*\"]\n\n1. 7\n",
+        )
+        .unwrap();
+        let input = format!("3\nX\nO\nload {}\n", record.display());
+        let (result, transcript) = run(&command, &input);
+        std::fs::remove_file(path).unwrap();
+        std::fs::remove_file(record).unwrap();
+        assert!(result.is_ok());
+        assert!(transcript.contains("Loaded the game from"));
+        assert!(transcript.contains("The engine selects 3\n"));
+    }
+
+    #[test]
+    fn engine_that_ignores_quit_is_killed() {
+        let path = std::env::temp_dir().join(format!(
+            "tic_tac_toe_engine_hangs_{}.sh",
+            std::process::id()
+        ));
+        let script =
+            "while read c; do case $c in go) echo bestmove 5;; esac; done; exec sleep 60\n";
+        std::fs::write(&path, script).unwrap();
+        let started = std::time::Instant::now();
+        let (result, _) = run(&format!("sh {}", path.display()), "3\nX\nO\n1\n");
+        std::fs::remove_file(path).unwrap();
+        assert!(result.is_ok());
+        assert!(started.elapsed() < std::time::Duration::from_secs(10));
+    }
+
+    #[test]
+    fn engine_that_stops_is_reported() {
+        let (result, _) = run("true", "3\nX\nO\n1\n");
+        assert!(matches!(result, Err(Error::EngineExited)));
+    }
+
+    #[test]
+    fn missing_engine_is_reported() {
+        let (result, _) = run("/nonexistent/engine", "3\n");
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+}
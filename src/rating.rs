@@ -0,0 +1,43 @@
+//! Provides functionality for the [Rating] enum
+
+use std::cmp::Ordering;
+
+use crate::Rating;
+
+// Private block
+impl Rating {
+    /// Ranks the rating so that quicker wins and slower losses come out higher.
+    fn rank(self) -> (u8, isize) {
+        match self {
+            Self::Loss(moves) => (0, moves as isize),
+            Self::Draw => (1, 0),
+            Self::Win(moves) => (2, -(moves as isize)),
+        }
+    }
+}
+
+impl Ord for Rating {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+impl PartialOrd for Rating {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for Rating {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let plural = |moves: usize| if moves == 1 { "" } else { "s" };
+        match self {
+            Self::Win(moves) => write!(f, "win in {} move{}", moves, plural(*moves)),
+            Self::Draw => write!(f, "draw"),
+            Self::Loss(moves) => write!(f, "loss in {} move{}", moves, plural(*moves)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
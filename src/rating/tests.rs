@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod rating_tests {
+    use crate::bit_patterns::Cell;
+    use crate::{Board, Player, Rating};
+
+    #[test]
+    fn quick_wins_beat_slow_wins_and_slow_losses_beat_quick_losses() {
+        let mut ratings = vec![
+            Rating::Loss(2),
+            Rating::Win(5),
+            Rating::Draw,
+            Rating::Loss(6),
+            Rating::Win(1),
+        ];
+        ratings.sort();
+        assert_eq!(
+            vec![
+                Rating::Loss(2),
+                Rating::Loss(6),
+                Rating::Draw,
+                Rating::Win(5),
+                Rating::Win(1),
+            ],
+            ratings
+        );
+    }
+
+    #[test]
+    fn ratings_are_described_in_moves() {
+        assert_eq!("win in 1 move", Rating::Win(1).to_string());
+        assert_eq!("loss in 4 moves", Rating::Loss(4).to_string());
+        assert_eq!("draw", Rating::Draw.to_string());
+    }
+
+    #[test]
+    fn every_legal_move_is_rated() {
+        let (board, to_move) = Board::from_notation("XX./OO./... x").unwrap();
+        let ratings = board.rate_moves(to_move);
+        assert_eq!(5, ratings.len());
+        assert!(ratings.contains(&(Cell::TopRight, Rating::Win(1))));
+        assert!(ratings.contains(&(Cell::BottomLeft, Rating::Loss(2))));
+        assert!(Board::new()
+            .rate_moves(Player::X)
+            .iter()
+            .all(|(_, rating)| *rating == Rating::Draw));
+    }
+}
@@ -0,0 +1,275 @@
+//! Provides functionality for the [`GameRecord`] struct
+//!
+//! A record is a PGN-style text file. It starts with a header per line, then
+//! lists every move with its number, using the same cell numbers the players type:
+//!
+//! ```text
+//! [Board "3x3"]
+//! [PlayerO "Human"]
+//! [PlayerX "Computer (Perfect)"]
+//! [Date "2026-10-18"]
+//! [Start "X"]
+//! [Result "X"]
+//!
+//! 1. 7 2. 1 3. 8 4. 2 5. 9
+//! ```
+//!
+//! Bigger boards add an `InARow` header, and Ultimate games use `[Board "Ultimate"]`.
+//! The result is `X`, `O`, `Draw`, or `*` for a game still in progress.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{Controller, Difficulty, GameRecord, Grid, Outcome, Player, Playfield, Ultimate};
+
+/// Gets today's date as `YYYY-MM-DD`, in UTC.
+fn today() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    // Converts days since 1970-01-01 to a civil date, counting eras of 400 years from 0000-03-01.
+    let days = (seconds / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Formats who controls a player, as written in a record.
+fn controller_name(controller: Controller) -> String {
+    match controller {
+        Controller::Human => "Human".to_owned(),
+        Controller::Computer(difficulty) => format!("Computer ({:?})", difficulty),
+        Controller::Remote => "Remote".to_owned(),
+        Controller::Engine => "Engine".to_owned(),
+    }
+}
+
+/// Parses who controls a player, as written in a record.
+fn parse_controller(name: &str) -> Result<Controller, String> {
+    match name {
+        // A remote player or engine can only carry on a loaded game in person.
+        "Human" | "Remote" | "Engine" => Ok(Controller::Human),
+        "Computer (Easy)" => Ok(Controller::Computer(Difficulty::Easy)),
+        "Computer (Medium)" => Ok(Controller::Computer(Difficulty::Medium)),
+        "Computer (Hard)" => Ok(Controller::Computer(Difficulty::Hard)),
+        "Computer (Perfect)" => Ok(Controller::Computer(Difficulty::Perfect)),
+        _ => Err(format!("{} is not a known player", name)),
+    }
+}
+
+/// Formats an [Outcome], as written in a record.
+const fn result_name(outcome: Outcome) -> &'static str {
+    match outcome {
+        Outcome::InProgress => "*",
+        Outcome::Win(player) => player_name(player),
+        Outcome::Draw => "Draw",
+    }
+}
+
+/// Formats a single player, as written in a record.
+pub(crate) const fn player_name(player: Player) -> &'static str {
+    match player {
+        Player::O => "O",
+        Player::X => "X",
+    }
+}
+
+/// Parses a single player, as written in a record.
+pub(crate) fn parse_player(name: &str) -> Result<Player, String> {
+    match name {
+        "O" => Ok(Player::O),
+        "X" => Ok(Player::X),
+        _ => Err(format!("{} is not a player", name)),
+    }
+}
+
+/// Parses a header line of the form `[Key "Value"]`.
+pub(crate) fn parse_header(line: &str) -> Result<(&str, &str), String> {
+    let invalid = || format!("{} is not a valid header", line);
+    let inner = line
+        .strip_prefix('[')
+        .and_then(|l| l.strip_suffix(']'))
+        .ok_or_else(invalid)?;
+    let (key, value) = inner.split_once(' ').ok_or_else(invalid)?;
+    let value = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .ok_or_else(invalid)?;
+    Ok((key, value))
+}
+
+// Private block
+impl GameRecord {
+    /// Gets the header that describes the board.
+    fn board_headers(&self) -> String {
+        match &self.playfield {
+            Playfield::Grid(grid) if grid.as_board().is_some() => "[Board \"3x3\"]\n".to_owned(),
+            Playfield::Grid(grid) => format!(
+                "[Board \"{size}x{size}\"]\n[InARow \"{}\"]\n",
+                grid.in_a_row(),
+                size = grid.size()
+            ),
+            Playfield::Ultimate(_) => "[Board \"Ultimate\"]\n".to_owned(),
+            Playfield::Misere(_) => "[Board \"Misere\"]\n".to_owned(),
+        }
+    }
+}
+
+// Public-Crate block
+impl GameRecord {
+    /// Builds the empty playfield described by the board headers.
+    pub(crate) fn parse_playfield(
+        board: &str,
+        in_a_row: Option<&str>,
+    ) -> Result<Playfield, String> {
+        match board {
+            "Ultimate" => return Ok(Playfield::Ultimate(Ultimate::new())),
+            "Misere" => return Ok(Playfield::Misere(Grid::classic())),
+            _ => (),
+        }
+        let invalid = || format!("{} is not a valid board", board);
+        let (size, other_size) = board.split_once('x').ok_or_else(invalid)?;
+        let size: usize = size.parse().map_err(|_| invalid())?;
+        if other_size.parse() != Ok(size) {
+            return Err(invalid());
+        }
+        let in_a_row = match in_a_row {
+            Some(n) => n
+                .parse()
+                .map_err(|_| format!("{} is not a valid number of cells in a row", n))?,
+            None => size,
+        };
+        Grid::new(size, in_a_row)
+            .map(Playfield::Grid)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Creates a record of a game, dated today.
+    pub(crate) fn new(
+        playfield: &Playfield,
+        controllers: (Controller, Controller),
+        start: Player,
+        moves: Vec<usize>,
+    ) -> Self {
+        Self {
+            playfield: playfield.cleared(),
+            player_o: controllers.0,
+            player_x: controllers.1,
+            date: today(),
+            start,
+            moves,
+        }
+    }
+
+    /// Parses a record, checking every move is legal by replaying the game.
+    pub(crate) fn parse(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines().map(str::trim).peekable();
+        let mut headers = Vec::new();
+        while let Some(line) = lines.next_if(|l| l.is_empty() || l.starts_with('[')) {
+            if !line.is_empty() {
+                headers.push(parse_header(line)?);
+            }
+        }
+        let header = |key: &str| headers.iter().find(|(k, _)| *k == key).map(|(_, v)| *v);
+        let required =
+            |key: &str| header(key).ok_or_else(|| format!("the {} header is missing", key));
+
+        let playfield = Self::parse_playfield(required("Board")?, header("InARow"))?;
+        let mut record = Self {
+            player_o: parse_controller(required("PlayerO")?)?,
+            player_x: parse_controller(required("PlayerX")?)?,
+            date: required("Date")?.to_owned(),
+            start: parse_player(required("Start")?)?,
+            moves: Vec::new(),
+            playfield,
+        };
+
+        let mut replay = record.playfield.cleared();
+        let mut player = record.start;
+        for token in lines.flat_map(str::split_whitespace) {
+            if let Some(number) = token.strip_suffix('.') {
+                if number.parse() != Ok(record.moves.len() + 1) {
+                    return Err(format!("{} is not the next move number", token));
+                }
+                continue;
+            }
+            let cell = replay.parse_cell(token).map_err(|e| e.to_string())?;
+            replay
+                .apply_move(player, cell)
+                .map_err(|e| format!("move {} is illegal: {}", record.moves.len() + 1, e))?;
+            record.moves.push(cell);
+            player.swap();
+        }
+
+        let result = required("Result")?;
+        if result != result_name(replay.outcome()) {
+            return Err(format!(
+                "the result {} does not match the moves, which give {}",
+                result,
+                result_name(replay.outcome())
+            ));
+        }
+        Ok(record)
+    }
+
+    /// Replays the recorded moves on an empty playfield.
+    pub(crate) fn replay(&self) -> Playfield {
+        let mut playfield = self.playfield.cleared();
+        let mut player = self.start;
+        for cell in &self.moves {
+            playfield
+                .apply_move(player, *cell)
+                .expect("recorded moves are legal");
+            player.swap();
+        }
+        playfield
+    }
+
+    /// Gets who controls players O and X.
+    pub(crate) const fn controllers(&self) -> (Controller, Controller) {
+        (self.player_o, self.player_x)
+    }
+
+    /// Gets the player who made the first move.
+    pub(crate) const fn start(&self) -> Player {
+        self.start
+    }
+
+    /// Gets the cell index of every move, in order.
+    pub(crate) fn moves(&self) -> &[usize] {
+        &self.moves
+    }
+}
+
+impl std::fmt::Display for GameRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let playfield = self.replay();
+        write!(f, "{}", self.board_headers())?;
+        writeln!(f, "[PlayerO \"{}\"]", controller_name(self.player_o))?;
+        writeln!(f, "[PlayerX \"{}\"]", controller_name(self.player_x))?;
+        writeln!(f, "[Date \"{}\"]", self.date)?;
+        writeln!(f, "[Start \"{}\"]", player_name(self.start))?;
+        writeln!(f, "[Result \"{}\"]", result_name(playfield.outcome()))?;
+        writeln!(f)?;
+        let moves: Vec<String> = self
+            .moves
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| format!("{}. {}", i + 1, playfield.cell_name(*cell)))
+            .collect();
+        writeln!(f, "{}", moves.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod record_tests {
+    use crate::{Controller, Difficulty, GameRecord, Grid, Outcome, Player, Playfield, Ultimate};
+
+    const CLASSIC_RECORD: &str = "[Board \"3x3\"]
+[PlayerO \"Human\"]
+[PlayerX \"Computer (Perfect)\"]
+[Date \"2026-10-18\"]
+[Start \"X\"]
+[Result \"X\"]
+
+1. 7 2. 1 3. 8 4. 2 5. 9
+";
+
+    fn classic_record_with(header: &str, value: &str) -> String {
+        CLASSIC_RECORD
+            .lines()
+            .map(|line| {
+                if line.starts_with(&format!("[{} ", header)) {
+                    format!("[{} \"{}\"]", header, value)
+                } else {
+                    line.to_owned()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn classic_record_round_trips() {
+        let record = GameRecord::parse(CLASSIC_RECORD).unwrap();
+        assert_eq!(Player::X, record.start());
+        assert_eq!(&[6, 0, 7, 1, 8], record.moves());
+        assert_eq!(
+            (Controller::Human, Controller::Computer(Difficulty::Perfect)),
+            record.controllers()
+        );
+        assert_eq!(Outcome::Win(Player::X), record.replay().outcome());
+        assert_eq!(CLASSIC_RECORD, record.to_string());
+    }
+
+    #[test]
+    fn new_record_writes_headers_and_moves() {
+        let playfield = Playfield::Grid(Grid::new(5, 4).unwrap());
+        let record = GameRecord::new(
+            &playfield,
+            (Controller::Computer(Difficulty::Easy), Controller::Human),
+            Player::O,
+            vec![0, 24, 12],
+        );
+        let text = record.to_string();
+        assert!(text.starts_with("[Board \"5x5\"]\n[InARow \"4\"]\n"));
+        assert!(text.contains("[PlayerO \"Computer (Easy)\"]\n"));
+        assert!(text.contains("[Start \"O\"]\n[Result \"*\"]\n"));
+        assert!(text.ends_with("\n1. 1 2. 25 3. 13\n"));
+
+        let parsed = GameRecord::parse(&text).unwrap();
+        assert_eq!(&[0, 24, 12], parsed.moves());
+        assert_eq!(Some(Player::X), parsed.replay().owner(24));
+    }
+
+    #[test]
+    fn ultimate_record_round_trips() {
+        let playfield = Playfield::Ultimate(Ultimate::new());
+        let record = GameRecord::new(
+            &playfield,
+            (Controller::Human, Controller::Human),
+            Player::X,
+            vec![40, 36],
+        );
+        let text = record.to_string();
+        assert!(text.contains("[Board \"Ultimate\"]\n"));
+        assert!(text.ends_with("\n1. 55 2. 51\n"));
+        assert_eq!(&[40, 36], GameRecord::parse(&text).unwrap().moves());
+    }
+
+    #[test]
+    fn illegal_moves_are_rejected() {
+        let text = CLASSIC_RECORD.replace("4. 2", "4. 7");
+        let error = GameRecord::parse(&text).unwrap_err();
+        assert_eq!("move 4 is illegal: cell 7 has already been set", error);
+
+        let text = CLASSIC_RECORD.replace("5. 9", "5. 9 6. 4");
+        assert!(GameRecord::parse(&text)
+            .unwrap_err()
+            .contains("already over"));
+    }
+
+    #[test]
+    fn mismatched_result_is_rejected() {
+        let error = GameRecord::parse(&classic_record_with("Result", "O")).unwrap_err();
+        assert_eq!("the result O does not match the moves, which give X", error);
+    }
+
+    #[test]
+    fn invalid_headers_are_rejected() {
+        let missing = CLASSIC_RECORD.replace("[Start \"X\"]\n", "");
+        assert_eq!(
+            "the Start header is missing",
+            GameRecord::parse(&missing).unwrap_err()
+        );
+        assert!(GameRecord::parse(&classic_record_with("Board", "3x4")).is_err());
+        assert!(GameRecord::parse(&classic_record_with("PlayerO", "Alice")).is_err());
+        assert!(GameRecord::parse(&classic_record_with("Start", "Z")).is_err());
+    }
+
+    #[test]
+    fn out_of_order_move_numbers_are_rejected() {
+        let text = CLASSIC_RECORD.replace("3. 8", "4. 8");
+        assert_eq!(
+            "4. is not the next move number",
+            GameRecord::parse(&text).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn misere_record_round_trips() {
+        let playfield = Playfield::Misere(Grid::classic());
+        let record = GameRecord::new(
+            &playfield,
+            (Controller::Human, Controller::Human),
+            Player::X,
+            vec![6, 0, 7, 1, 8],
+        );
+        let text = record.to_string();
+        assert!(text.contains("[Board \"Misere\"]\n"));
+        let parsed = GameRecord::parse(&text).unwrap();
+        assert_eq!(Outcome::Win(Player::O), parsed.replay().outcome());
+    }
+}
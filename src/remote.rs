@@ -0,0 +1,99 @@
+//! Provides functionality for the [Remote] struct
+//!
+//! Two instances play a network game by exchanging lines of text over TCP.
+//! The host opens with the board and the starting player, then each side sends its own moves
+//! as the move number followed by the cell, numbered as they are typed:
+//!
+//! ```text
+//! hello 3x3
+//! start X
+//! move 1 5
+//! move 2 1
+//! ```
+//!
+//! Bigger boards are sent with the number of cells in a row needed to win, such as `hello 5x5 4`.
+//! Ultimate Tic-Tac-Toe is sent as `hello Ultimate` and Misère as `hello Misere`.
+//! Each side checks every move against its own board, so a move that is out of step or illegal
+//! is reported instead of played.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+
+use crate::record::{parse_player, player_name};
+use crate::{Error, Player, Playfield, Remote};
+
+// Private block
+impl Remote {
+    /// Sends a line to the other instance.
+    fn send(&mut self, message: &str) -> Result<(), Error> {
+        writeln!(self.writer, "{}", message)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Waits for the next line from the other instance.
+    ///
+    /// The connection closing is reported as [`Error::Disconnected`].
+    fn receive(&mut self) -> Result<String, Error> {
+        let mut buffer = String::new();
+        if self.reader.read_line(&mut buffer)? == 0 {
+            return Err(Error::Disconnected);
+        }
+        Ok(buffer.trim().to_owned())
+    }
+}
+
+// Public-Crate block
+impl Remote {
+    /// Wraps a connection to the other instance.
+    pub(crate) fn new(stream: TcpStream) -> Result<Self, Error> {
+        stream.set_nodelay(true)?;
+        Ok(Self {
+            writer: stream.try_clone()?,
+            reader: BufReader::new(stream),
+        })
+    }
+
+    /// Tells the joining instance which board to play on and who starts.
+    pub(crate) fn send_setup(&mut self, playfield: &Playfield, start: Player) -> Result<(), Error> {
+        self.send(&format!("hello {}", playfield.name()))?;
+        self.send(&format!("start {}", player_name(start)))
+    }
+
+    /// Waits for the host to say which board to play on and who starts.
+    pub(crate) fn receive_setup(&mut self) -> Result<(Playfield, Player), Error> {
+        let hello = self.receive()?;
+        let playfield = hello
+            .strip_prefix("hello ")
+            .and_then(Playfield::from_name)
+            .ok_or_else(|| Error::Protocol(hello.clone()))?;
+
+        let start = self.receive()?;
+        let player = start
+            .strip_prefix("start ")
+            .and_then(|name| parse_player(name).ok())
+            .ok_or_else(|| Error::Protocol(start.clone()))?;
+        Ok((playfield, player))
+    }
+
+    /// Sends a move made on this instance, given its number and the name of its cell.
+    pub(crate) fn send_move(&mut self, number: usize, cell: &str) -> Result<(), Error> {
+        self.send(&format!("move {} {}", number, cell))
+    }
+
+    /// Waits for the other instance's move, returning its number and the name of its cell.
+    pub(crate) fn receive_move(&mut self) -> Result<(usize, String), Error> {
+        let line = self.receive()?;
+        let mut words = line.split_whitespace();
+        match (words.next(), words.next(), words.next(), words.next()) {
+            (Some("move"), Some(number), Some(cell), None) => match number.parse() {
+                Ok(number) => Ok((number, cell.to_owned())),
+                Err(_) => Err(Error::Protocol(line)),
+            },
+            _ => Err(Error::Protocol(line)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
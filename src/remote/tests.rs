@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod remote_tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+
+    use crate::{host_with, join_with, Error};
+
+    type Transcript = (Result<(), Error>, String);
+
+    /// Hosts a game on a loopback port, returning the port and a handle to the host's transcript.
+    fn host(input: &'static str) -> (u16, thread::JoinHandle<Transcript>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let handle = thread::spawn(move || {
+            let mut output = Vec::new();
+            let result = host_with(&listener, input.as_bytes(), &mut output);
+            (result, String::from_utf8(output).unwrap())
+        });
+        (port, handle)
+    }
+
+    /// Joins the game on the given loopback port.
+    fn join(port: u16, input: &str) -> Transcript {
+        let stream = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        let mut output = Vec::new();
+        let result = join_with(stream, input.as_bytes(), &mut output);
+        (result, String::from_utf8(output).unwrap())
+    }
+
+    /// Joins a host that sends the given lines, then reads whatever comes back.
+    fn join_scripted_host(lines: &'static str, input: &str) -> Transcript {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            stream.write_all(lines.as_bytes()).unwrap();
+            BufReader::new(stream).lines().count()
+        });
+        let transcript = join(port, input);
+        handle.join().unwrap();
+        transcript
+    }
+
+    #[test]
+    fn two_players_finish_a_game_over_loopback() {
+        let (port, host) = host("3\nX\n7\n8\n9\n");
+        let (result, joined) = join(port, "1\n2\n");
+        let (host_result, hosted) = host.join().unwrap();
+
+        assert!(host_result.is_ok());
+        assert!(result.is_ok());
+        assert!(hosted.contains("has joined the game\n"));
+        assert!(hosted.contains("You are Player X\n"));
+        assert!(hosted.contains("The remote player selects 1\n"));
+        assert!(hosted.contains("Player X has won!\n"));
+        assert!(joined.contains("You are Player O\n"));
+        assert!(joined.contains("The remote player selects 9\n"));
+        assert!(joined.contains("Player X has won!\n"));
+        assert!(!joined.contains("Would you like to play again?"));
+    }
+
+    #[test]
+    fn host_chooses_the_board() {
+        let (port, host) = host("U\nX\n55\n");
+        let (result, joined) = join(port, "");
+        let (host_result, _) = host.join().unwrap();
+
+        assert!(matches!(host_result, Err(Error::Disconnected)));
+        assert!(result.is_ok());
+        assert!(joined.contains("Player X Begins.\n"));
+        assert!(joined.contains("The remote player selects 55\n"));
+        assert!(joined.contains("Enter a value between 1-9 for sub-board 5\n"));
+    }
+
+    #[test]
+    fn illegal_remote_move_is_reported() {
+        let (result, transcript) =
+            join_scripted_host("hello 3x3\nstart X\nmove 1 5\nmove 3 5\n", "1\n");
+        assert!(matches!(
+            result,
+            Err(Error::IllegalRemoteMove(e)) if matches!(*e, Error::CellOccupied(5))
+        ));
+        assert!(transcript.contains("The remote player selects 5\n"));
+    }
+
+    #[test]
+    fn move_out_of_step_is_reported() {
+        let (result, _) = join_scripted_host("hello 3x3\nstart X\nmove 2 5\n", "");
+        assert!(matches!(
+            result,
+            Err(Error::Desync {
+                expected: 1,
+                found: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn unknown_board_is_a_protocol_error() {
+        let (result, _) = join_scripted_host("hello 2x2\nstart X\n", "");
+        assert!(matches!(result, Err(Error::Protocol(line)) if line == "hello 2x2"));
+    }
+
+    #[test]
+    fn undo_is_refused_in_a_network_game() {
+        let (result, transcript) = join_scripted_host("hello 3x3\nstart O\n", "u\nload game.txt\n");
+        assert!(result.is_ok());
+        assert_eq!(
+            2,
+            transcript
+                .matches("Sorry, that cannot be done in a network game.\n")
+                .count()
+        );
+    }
+}
@@ -0,0 +1,59 @@
+//! Provides functionality for the [Rng] struct
+//!
+//! The generator is a xorshift64* generator, which is plenty for picking
+//! moves and keeps the crate free of dependencies.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::Rng;
+
+// Public block
+impl Rng {
+    /// Creates a generator from a seed. The same seed always gives the same sequence.
+    pub const fn new(seed: u64) -> Self {
+        // A zero state would only ever produce zeroes, so the seed is mixed with a constant.
+        const MIX: u64 = 0x9E37_79B9_7F4A_7C15;
+        let state = seed ^ MIX;
+        Self {
+            state: if state == 0 { MIX } else { state },
+        }
+    }
+
+    /// Creates a generator seeded from the system clock.
+    pub fn from_time() -> Self {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64);
+        Self::new(nanos)
+    }
+
+    /// Gets the next random number.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Gets a random number in the range `0..bound`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "bound must be greater than zero");
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// Picks a random item from a slice, or [None] if it is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            None
+        } else {
+            items.get(self.below(items.len()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
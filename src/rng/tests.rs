@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod rng_tests {
+    use crate::Rng;
+
+    #[test]
+    fn same_seed_gives_same_sequence() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn different_seeds_give_different_sequences() {
+        let mut a = Rng::new(1);
+        let mut b = Rng::new(2);
+        assert!((0..10).any(|_| a.next_u64() != b.next_u64()));
+    }
+
+    #[test]
+    fn below_stays_in_range_and_covers_it() {
+        let mut rng = Rng::new(0);
+        let mut seen = [false; 9];
+        for _ in 0..1000 {
+            let n = rng.below(9);
+            assert!(n < 9);
+            seen[n] = true;
+        }
+        assert!(seen.iter().all(|s| *s));
+    }
+
+    #[test]
+    fn choose_from_empty_slice_is_none() {
+        let empty: [u8; 0] = [];
+        assert_eq!(None, Rng::new(5).choose(&empty));
+    }
+}
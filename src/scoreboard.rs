@@ -0,0 +1,70 @@
+//! Provides functionality for the [Scoreboard] struct
+
+use crate::{Outcome, Player, Scoreboard};
+
+// Public-Crate block
+impl Scoreboard {
+    /// Adds the result of a finished game. Games still in progress are not counted.
+    pub(crate) fn record(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Win(Player::O) => self.o_wins += 1,
+            Outcome::Win(Player::X) => self.x_wins += 1,
+            Outcome::Draw => self.draws += 1,
+            Outcome::InProgress => (),
+        }
+    }
+
+    /// Counts every finished game.
+    pub(crate) const fn games(&self) -> usize {
+        self.o_wins + self.x_wins + self.draws
+    }
+
+    /// Counts the games the given player has won.
+    pub(crate) const fn wins(&self, player: Player) -> usize {
+        match player {
+            Player::O => self.o_wins,
+            Player::X => self.x_wins,
+        }
+    }
+
+    /// Counts the games the given player has lost.
+    pub(crate) const fn losses(&self, player: Player) -> usize {
+        self.wins(player.opponent())
+    }
+
+    /// Gets the player who has won the most games, or [None] if they are level.
+    pub(crate) fn leader(&self) -> Option<Player> {
+        match self.o_wins.cmp(&self.x_wins) {
+            std::cmp::Ordering::Greater => Some(Player::O),
+            std::cmp::Ordering::Less => Some(Player::X),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Checks whether a best-of match is over, either because a player has won
+    /// most of its games or because every game has been played.
+    pub(crate) fn is_decided(&self, best_of: usize) -> bool {
+        2 * self.o_wins.max(self.x_wins) > best_of || self.games() >= best_of
+    }
+
+    /// Lays out every player's wins, draws and losses in a table, under the names given.
+    pub(crate) fn summary<F: Fn(Player) -> String>(&self, name: F) -> String {
+        let names = [name(Player::O), name(Player::X)];
+        let width = names.iter().map(String::len).max().unwrap_or(0).max(6);
+        let mut summary = format!("Games played: {}\n", self.games());
+        summary.push_str(&format!("{:<width$} | Won | Drawn | Lost\n", "Player"));
+        for (player, name) in [Player::O, Player::X].iter().zip(&names) {
+            summary.push_str(&format!(
+                "{:<width$} | {:>3} | {:>5} | {:>4}\n",
+                name,
+                self.wins(*player),
+                self.draws,
+                self.losses(*player)
+            ));
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod scoreboard_tests {
+    use crate::{Outcome, Player, Scoreboard};
+
+    fn scoreboard(outcomes: &[Outcome]) -> Scoreboard {
+        let mut scoreboard = Scoreboard::default();
+        for outcome in outcomes {
+            scoreboard.record(*outcome);
+        }
+        scoreboard
+    }
+
+    #[test]
+    fn results_are_counted_for_both_players() {
+        let scoreboard = scoreboard(&[
+            Outcome::Win(Player::X),
+            Outcome::Draw,
+            Outcome::Win(Player::O),
+            Outcome::Win(Player::X),
+            Outcome::InProgress,
+        ]);
+        assert_eq!(4, scoreboard.games());
+        assert_eq!(2, scoreboard.wins(Player::X));
+        assert_eq!(1, scoreboard.losses(Player::X));
+        assert_eq!(1, scoreboard.wins(Player::O));
+        assert_eq!(2, scoreboard.losses(Player::O));
+        assert_eq!(Some(Player::X), scoreboard.leader());
+    }
+
+    #[test]
+    fn level_players_have_no_leader() {
+        assert_eq!(None, Scoreboard::default().leader());
+        let level = scoreboard(&[Outcome::Win(Player::O), Outcome::Win(Player::X)]);
+        assert_eq!(None, level.leader());
+    }
+
+    #[test]
+    fn matches_end_on_a_majority_or_after_every_game() {
+        let one_win = scoreboard(&[Outcome::Win(Player::O)]);
+        assert!(!one_win.is_decided(3));
+        assert!(one_win.is_decided(1));
+        let two_wins = scoreboard(&[
+            Outcome::Win(Player::O),
+            Outcome::Draw,
+            Outcome::Win(Player::O),
+        ]);
+        assert!(two_wins.is_decided(3));
+        assert!(!two_wins.is_decided(5));
+        let draws = scoreboard(&[Outcome::Draw, Outcome::Draw, Outcome::Draw]);
+        assert!(draws.is_decided(3));
+        assert!(!draws.is_decided(4));
+    }
+
+    #[test]
+    fn summary_lists_each_player() {
+        let summary =
+            scoreboard(&[Outcome::Win(Player::X), Outcome::Draw]).summary(|p| p.to_string());
+        assert_eq!(
+            "Games played: 2\n\
+             Player   | Won | Drawn | Lost\n\
+             Player O |   0 |     1 |    1\n\
+             Player X |   1 |     1 |    0\n",
+            summary
+        );
+    }
+
+    #[test]
+    fn summary_widens_for_long_names() {
+        let summary = scoreboard(&[Outcome::Win(Player::O)]).summary(|player| match player {
+            Player::O => "Alexandra".to_owned(),
+            Player::X => "Bo".to_owned(),
+        });
+        assert_eq!(
+            "Games played: 1\n\
+             Player    | Won | Drawn | Lost\n\
+             Alexandra |   1 |     0 |    0\n\
+             Bo        |   0 |     0 |    1\n",
+            summary
+        );
+    }
+}
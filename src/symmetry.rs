@@ -0,0 +1,115 @@
+//! Provides functionality for the [Symmetry] enum
+//!
+//! Each cell of a [Board] takes two bits, so moving a cell to another position
+//! is a shift by twice the distance between their indexes. Every symmetry is
+//! built from three such permutations: swapping the left and right columns,
+//! swapping the top and bottom rows, and reflecting across the main diagonal.
+
+use crate::bit_patterns::Cell;
+use crate::{Board, Symmetry};
+
+const LEFT_COLUMN: u32 = 0b11_0000_1100_0011_0000;
+const CENTRE_COLUMN: u32 = 0b00_1100_0011_0000_1100;
+const RIGHT_COLUMN: u32 = 0b00_0011_0000_1100_0011;
+const TOP_ROW: u32 = 0b11_1111_0000_0000_0000;
+const CENTRE_ROW: u32 = 0b00_0000_1111_1100_0000;
+const BOTTOM_ROW: u32 = 0b00_0000_0000_0011_1111;
+const MAIN_DIAGONAL: u32 = 0b11_0000_0011_0000_0011;
+/// The top-centre and centre-right cells, one step above the main diagonal.
+const ABOVE_DIAGONAL: u32 = 0b00_1100_0000_1100_0000;
+/// The centre-left and bottom-centre cells, one step below the main diagonal.
+const BELOW_DIAGONAL: u32 = 0b00_0000_1100_0000_1100;
+const TOP_RIGHT: u32 = 0b00_0011_0000_0000_0000;
+const BOTTOM_LEFT: u32 = 0b00_0000_0000_0011_0000;
+
+/// Swaps the left and right columns.
+const fn mirror_left_right(cells: u32) -> u32 {
+    (cells & CENTRE_COLUMN) | ((cells & LEFT_COLUMN) >> 4) | ((cells & RIGHT_COLUMN) << 4)
+}
+
+/// Swaps the top and bottom rows.
+const fn mirror_top_bottom(cells: u32) -> u32 {
+    (cells & CENTRE_ROW) | ((cells & TOP_ROW) >> 12) | ((cells & BOTTOM_ROW) << 12)
+}
+
+/// Reflects across the diagonal from the top-left to the bottom-right.
+const fn transpose(cells: u32) -> u32 {
+    (cells & MAIN_DIAGONAL)
+        | ((cells & ABOVE_DIAGONAL) >> 4)
+        | ((cells & BELOW_DIAGONAL) << 4)
+        | ((cells & TOP_RIGHT) >> 8)
+        | ((cells & BOTTOM_LEFT) << 8)
+}
+
+// Public block
+impl Symmetry {
+    /// Every symmetry, starting with the identity.
+    pub const ALL: [Self; 8] = [
+        Self::Identity,
+        Self::Rotate90,
+        Self::Rotate180,
+        Self::Rotate270,
+        Self::MirrorLeftRight,
+        Self::MirrorTopBottom,
+        Self::MirrorMainDiagonal,
+        Self::MirrorAntiDiagonal,
+    ];
+
+    /// Gets the symmetry that undoes this one.
+    pub const fn inverse(self) -> Self {
+        match self {
+            Self::Rotate90 => Self::Rotate270,
+            Self::Rotate270 => Self::Rotate90,
+            other => other,
+        }
+    }
+
+    /// Moves the bits of every cell to where this symmetry takes them.
+    pub const fn permute(self, cells: u32) -> u32 {
+        match self {
+            Self::Identity => cells,
+            Self::Rotate90 => mirror_left_right(transpose(cells)),
+            Self::Rotate180 => mirror_top_bottom(mirror_left_right(cells)),
+            Self::Rotate270 => mirror_top_bottom(transpose(cells)),
+            Self::MirrorLeftRight => mirror_left_right(cells),
+            Self::MirrorTopBottom => mirror_top_bottom(cells),
+            Self::MirrorMainDiagonal => transpose(cells),
+            Self::MirrorAntiDiagonal => mirror_top_bottom(mirror_left_right(transpose(cells))),
+        }
+    }
+
+    /// Gets the cell this symmetry moves the given cell to.
+    pub fn cell(self, cell: Cell) -> Cell {
+        let moved = self.permute(cell as u32);
+        Cell::ALL
+            .iter()
+            .copied()
+            .find(|c| *c as u32 == moved)
+            .unwrap_or(cell)
+    }
+}
+
+// Public block
+impl Board {
+    /// Applies a [Symmetry] to the board.
+    pub const fn transformed(&self, symmetry: Symmetry) -> Self {
+        Self {
+            cells: symmetry.permute(self.cells),
+        }
+    }
+
+    /// Finds the canonical form of the board, which every symmetric board shares,
+    /// along with the [Symmetry] that turns this board into it.
+    ///
+    /// The canonical form is the symmetric board with the smallest encoding.
+    pub fn canonical(&self) -> (Self, Symmetry) {
+        Symmetry::ALL
+            .iter()
+            .map(|symmetry| (self.transformed(*symmetry), *symmetry))
+            .min_by_key(|(board, _)| board.cells)
+            .unwrap_or((*self, Symmetry::Identity))
+    }
+}
+
+#[cfg(test)]
+mod tests;
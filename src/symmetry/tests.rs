@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod symmetry_tests {
+    use crate::bit_patterns::Cell;
+    use crate::{Board, Symmetry, WinPattern};
+
+    fn board(notation: &str) -> Board {
+        Board::from_notation(notation).unwrap().0
+    }
+
+    #[test]
+    fn symmetries_move_cells_around_the_square() {
+        let cases = [
+            (Symmetry::Identity, "X../.../... o"),
+            (Symmetry::Rotate90, "..X/.../... o"),
+            (Symmetry::Rotate180, ".../.../..X o"),
+            (Symmetry::Rotate270, ".../.../X.. o"),
+            (Symmetry::MirrorLeftRight, "..X/.../... o"),
+            (Symmetry::MirrorTopBottom, ".../.../X.. o"),
+            (Symmetry::MirrorMainDiagonal, "X../.../... o"),
+            (Symmetry::MirrorAntiDiagonal, ".../.../..X o"),
+        ];
+        let start = board("X../.../... o");
+        for (symmetry, expected) in cases {
+            assert_eq!(
+                board(expected),
+                start.transformed(symmetry),
+                "{:?}",
+                symmetry
+            );
+        }
+    }
+
+    #[test]
+    fn rotations_turn_a_quarter_at_a_time() {
+        let start = board("XO./.../... x");
+        assert_eq!(
+            board("..X/..O/... x"),
+            start.transformed(Symmetry::Rotate90)
+        );
+        assert_eq!(
+            board(".../.../.OX x"),
+            start.transformed(Symmetry::Rotate180)
+        );
+        assert_eq!(
+            board(".../O../X.. x"),
+            start.transformed(Symmetry::Rotate270)
+        );
+        assert_eq!(
+            start.transformed(Symmetry::Rotate180),
+            start
+                .transformed(Symmetry::Rotate90)
+                .transformed(Symmetry::Rotate90)
+        );
+    }
+
+    #[test]
+    fn inverse_undoes_every_symmetry() {
+        let start = board("XO./.X./..O x");
+        for symmetry in Symmetry::ALL {
+            assert_eq!(
+                start,
+                start.transformed(symmetry).transformed(symmetry.inverse()),
+                "{:?}",
+                symmetry
+            );
+        }
+    }
+
+    #[test]
+    fn symmetries_keep_win_patterns_whole() {
+        for symmetry in Symmetry::ALL {
+            for pattern in WinPattern::ALL {
+                let moved = symmetry.permute(pattern as u32);
+                assert!(
+                    WinPattern::ALL.iter().any(|p| *p as u32 == moved),
+                    "{:?} broke {:?}",
+                    symmetry,
+                    pattern
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn cell_follows_the_board() {
+        for symmetry in Symmetry::ALL {
+            for cell in Cell::ALL {
+                let mut start = Board::new();
+                start.set_cell(crate::Player::X, cell);
+                let mut moved = Board::new();
+                moved.set_cell(crate::Player::X, symmetry.cell(cell));
+                assert_eq!(moved, start.transformed(symmetry));
+            }
+        }
+    }
+
+    #[test]
+    fn symmetric_boards_share_a_canonical_form() {
+        let start = board("XO./.X./..O x");
+        let (canonical, symmetry) = start.canonical();
+        assert_eq!(canonical, start.transformed(symmetry));
+        for other in Symmetry::ALL {
+            assert_eq!(canonical, start.transformed(other).canonical().0);
+        }
+        assert_eq!((Board::new(), Symmetry::Identity), Board::new().canonical());
+    }
+
+    #[test]
+    fn openings_reduce_to_three_canonical_boards() {
+        let mut canonical: Vec<Board> = Cell::ALL
+            .iter()
+            .map(|cell| {
+                let mut board = Board::new();
+                board.set_cell(crate::Player::X, *cell);
+                board.canonical().0
+            })
+            .collect();
+        canonical.sort_by_key(|board| board.cells);
+        canonical.dedup();
+        assert_eq!(3, canonical.len());
+    }
+}
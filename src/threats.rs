@@ -0,0 +1,78 @@
+//! Provides threat and fork detection for the [Board] struct.
+//!
+//! Everything here works on masks over the "set" bit of each cell, the same
+//! bits a [WinPattern] covers, so a line is checked with a single `&`.
+
+use crate::bit_patterns::{Cell, WinPattern};
+use crate::{Board, Player};
+
+/// The "set" bit of every cell.
+const SET_BITS: u32 = 0b10_1010_1010_1010_1010;
+
+/// Lists the cells in a mask of "set" bits, in cell number order.
+fn cells_in(mask: u32) -> Vec<Cell> {
+    Cell::ALL
+        .iter()
+        .copied()
+        .filter(|cell| mask & *cell as u32 != 0)
+        .collect()
+}
+
+/// Finds the empty cells that would complete a line of `own` cells.
+fn threats(own: u32, empty: u32) -> u32 {
+    WinPattern::ALL
+        .iter()
+        .map(|pattern| *pattern as u32)
+        .filter(|pattern| (own & pattern).count_ones() == 2)
+        .fold(0, |threats, pattern| threats | (empty & pattern))
+}
+
+// Private block
+impl Board {
+    /// Gets the "set" bit of every cell the player has set.
+    const fn cells_of(&self, player: Player) -> u32 {
+        let x = self.cells & (self.cells << 1) & SET_BITS;
+        match player {
+            Player::X => x,
+            Player::O => self.cells & SET_BITS & !x,
+        }
+    }
+
+    /// Gets the "set" bit of every empty cell, or nothing once the game is over.
+    fn empty_cells(&self) -> u32 {
+        if self.has_player_won() {
+            0
+        } else {
+            !self.cells & SET_BITS
+        }
+    }
+}
+
+// Public block
+impl Board {
+    /// Lists the cells where the player would complete a [WinPattern] straight away.
+    pub fn winning_moves(&self, player: Player) -> Vec<Cell> {
+        cells_in(threats(self.cells_of(player), self.empty_cells()))
+    }
+
+    /// Lists the cells the player must take to stop the opponent winning on their next move.
+    pub fn blocking_moves(&self, player: Player) -> Vec<Cell> {
+        self.winning_moves(player.opponent())
+    }
+
+    /// Lists the cells where the player would threaten to win in two places at once.
+    pub fn fork_moves(&self, player: Player) -> Vec<Cell> {
+        let own = self.cells_of(player);
+        let empty = self.empty_cells();
+        cells_in(
+            cells_in(empty)
+                .into_iter()
+                .map(|cell| cell as u32)
+                .filter(|cell| threats(own | cell, empty & !cell).count_ones() >= 2)
+                .fold(0, |forks, cell| forks | cell),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod threat_tests {
+    use crate::bit_patterns::Cell;
+    use crate::{Board, Player};
+
+    fn board(notation: &str) -> Board {
+        Board::from_notation(notation).unwrap().0
+    }
+
+    #[test]
+    fn winning_moves_complete_a_line() {
+        let board = board("XX./OO./... x");
+        assert_eq!(vec![Cell::TopRight], board.winning_moves(Player::X));
+        assert_eq!(vec![Cell::CentreRight], board.winning_moves(Player::O));
+        assert!(Board::new().winning_moves(Player::X).is_empty());
+    }
+
+    #[test]
+    fn blocking_moves_stop_the_opponent() {
+        assert!(board("X../.O./..X o").blocking_moves(Player::O).is_empty());
+        assert_eq!(
+            vec![Cell::CentreRight, Cell::TopCentre],
+            board("X.X/.O./..X o").blocking_moves(Player::O)
+        );
+    }
+
+    #[test]
+    fn forks_threaten_two_lines_at_once() {
+        // X in opposite corners with O in the centre, so either free corner makes two threats.
+        let board = board("X../.O./..X x");
+        assert_eq!(
+            vec![Cell::BottomLeft, Cell::TopRight],
+            board.fork_moves(Player::X)
+        );
+        assert!(board.fork_moves(Player::O).is_empty());
+    }
+
+    #[test]
+    fn finished_boards_have_no_threats() {
+        let board = board("XXX/OO./... o");
+        assert!(board.winning_moves(Player::O).is_empty());
+        assert!(board.blocking_moves(Player::X).is_empty());
+        assert!(board.fork_moves(Player::O).is_empty());
+    }
+
+    #[test]
+    fn threats_agree_with_playing_the_move() {
+        for board in Board::reachable_positions() {
+            for player in [Player::X, Player::O] {
+                for cell in board.legal_moves() {
+                    let mut next = board;
+                    next.set_cell(player, cell);
+                    assert_eq!(
+                        next.winner() == Some(player),
+                        board.winning_moves(player).contains(&cell),
+                        "\n{}",
+                        board
+                    );
+                }
+            }
+        }
+    }
+}
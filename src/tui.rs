@@ -0,0 +1,348 @@
+//! Provides a full-screen terminal interface for a [Game]
+//!
+//! The board is redrawn in place after every key press. Cells are chosen by moving the cursor
+//! with the arrow keys and pressing Enter or Space, by clicking them, or by typing their number.
+
+use std::io::{self, BufRead, Stdout, Write};
+use std::time::Duration;
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, MouseButton,
+    MouseEvent, MouseEventKind,
+};
+use crossterm::style::{
+    Attribute, Color, Print, ResetColor, SetAttribute, SetBackgroundColor, SetForegroundColor,
+};
+use crossterm::terminal::{
+    self, disable_raw_mode, enable_raw_mode, Clear, ClearType, EnterAlternateScreen,
+    LeaveAlternateScreen,
+};
+use crossterm::{execute, queue};
+
+use crate::{Controller, Error, Game, NextGame, Outcome, Player, Playfield};
+
+/// The column the board starts in.
+const LEFT: u16 = 2;
+/// The row the board starts in.
+const TOP: u16 = 2;
+/// How long to wait before each computer move, so the players can follow the game.
+const COMPUTER_DELAY: Duration = Duration::from_millis(400);
+
+/// Where the cells of a [Playfield] are drawn on screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Layout {
+    dimension: usize,
+    /// Whether the cells are grouped into the sub-boards of Ultimate Tic-Tac-Toe.
+    blocks: bool,
+}
+
+impl Layout {
+    /// Lays out the cells of the given playfield.
+    fn new(playfield: &Playfield) -> Self {
+        Self {
+            dimension: playfield.dimension(),
+            blocks: matches!(playfield, Playfield::Ultimate(_)),
+        }
+    }
+
+    /// Gets the space left before a row or column, which separates sub-boards.
+    const fn gap(self, position: usize) -> u16 {
+        if self.blocks {
+            (position / 3) as u16
+        } else {
+            0
+        }
+    }
+
+    /// Gets the screen position of the left border of a cell.
+    const fn origin(self, row: usize, column: usize) -> (u16, u16) {
+        (
+            LEFT + 4 * column as u16 + 2 * self.gap(column),
+            TOP + 1 + 2 * row as u16 + 2 * self.gap(row),
+        )
+    }
+
+    /// Finds the row and column of the cell drawn at a screen position, if any.
+    fn cell_at(self, x: u16, y: u16) -> Option<(usize, usize)> {
+        (0..self.dimension)
+            .flat_map(|row| (0..self.dimension).map(move |column| (row, column)))
+            .find(|(row, column)| {
+                let (left, top) = self.origin(*row, *column);
+                y == top && x > left && x < left + 4
+            })
+    }
+
+    /// Gets the first screen row below the board.
+    const fn bottom(self) -> u16 {
+        self.origin(self.dimension - 1, 0).1 + 2
+    }
+}
+
+/// Takes over the terminal, and gives it back when dropped, even if the game fails.
+struct Screen {
+    out: Stdout,
+}
+
+impl Screen {
+    /// Switches to a blank screen that reports key presses and mouse clicks.
+    fn enter() -> Result<Self, Error> {
+        let mut out = io::stdout();
+        enable_raw_mode()?;
+        execute!(out, EnterAlternateScreen, EnableMouseCapture, Hide)?;
+        Ok(Self { out })
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        // There is nothing more to do if the terminal cannot be restored.
+        let _ = execute!(self.out, Show, DisableMouseCapture, LeaveAlternateScreen);
+        let _ = disable_raw_mode();
+    }
+}
+
+/// Gets the colour a player's marks are drawn in.
+const fn colour(player: Player) -> Color {
+    match player {
+        Player::O => Color::Cyan,
+        Player::X => Color::Red,
+    }
+}
+
+/// Gets the mark a player sets.
+const fn mark(player: Player) -> char {
+    match player {
+        Player::O => 'O',
+        Player::X => 'X',
+    }
+}
+
+// Private block
+impl<R: BufRead, W: Write> Game<R, W> {
+    /// Says what the players can do once a game is over.
+    fn after_game(&self) -> String {
+        match self.next_game() {
+            NextGame::Offer => "Press N to play again or Q to quit.".to_owned(),
+            NextGame::Match => "Press N for the next game of the match or Q to quit.".to_owned(),
+            NextGame::Stop => match self.match_result() {
+                Some(result) => format!("{} Press Q to quit.", result),
+                None => "Press Q to quit.".to_owned(),
+            },
+        }
+    }
+
+    /// Describes whose turn it is, or how the game ended.
+    fn status(&self) -> String {
+        match self.board.outcome() {
+            Outcome::Win(winner) => {
+                let loser = match self.board {
+                    Playfield::Misere(_) => format!("{} has lost! ", self.name(winner.opponent())),
+                    Playfield::Grid(_) | Playfield::Ultimate(_) => String::new(),
+                };
+                format!(
+                    "{}{} has won! {}",
+                    loser,
+                    self.name(winner),
+                    self.after_game()
+                )
+            }
+            Outcome::Draw => format!("Draw! {}", self.after_game()),
+            Outcome::InProgress => {
+                let name = self.name(self.current_player);
+                let mover = match self.controller(self.current_player) {
+                    Controller::Human => "",
+                    _ => " (thinking)",
+                };
+                match &self.board {
+                    Playfield::Ultimate(ultimate) => match ultimate.forced_board() {
+                        Some(board) => format!("{}{} to move in sub-board {}", name, mover, board),
+                        None => format!("{}{} to move in any sub-board", name, mover),
+                    },
+                    Playfield::Grid(_) | Playfield::Misere(_) => {
+                        format!("{}{} to move", name, mover)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Redraws the whole screen, with the cursor on the given cell and an optional message.
+    fn draw(
+        &self,
+        screen: &mut Screen,
+        cursor: (usize, usize),
+        message: Option<&str>,
+    ) -> Result<(), Error> {
+        let layout = Layout::new(&self.board);
+        let winning_line = self.board.winning_line();
+        let out = &mut screen.out;
+        queue!(
+            out,
+            Clear(ClearType::All),
+            MoveTo(LEFT, 0),
+            Print("Tic-Tac-Toe")
+        )?;
+
+        for row in 0..layout.dimension {
+            for column in 0..layout.dimension {
+                let index = self.board.index_at(row, column);
+                let (x, y) = layout.origin(row, column);
+                queue!(
+                    out,
+                    MoveTo(x, y - 1),
+                    Print("+---+"),
+                    MoveTo(x, y),
+                    Print("|")
+                )?;
+                let mark = match self.board.owner(index) {
+                    Some(player) => {
+                        queue!(out, SetForegroundColor(colour(player)))?;
+                        mark(player)
+                    }
+                    None => ' ',
+                };
+                if winning_line.contains(&index) {
+                    queue!(
+                        out,
+                        SetBackgroundColor(Color::Green),
+                        SetForegroundColor(Color::Black)
+                    )?;
+                }
+                if (row, column) == cursor {
+                    queue!(out, SetAttribute(Attribute::Reverse))?;
+                }
+                queue!(
+                    out,
+                    Print(format!(" {} ", mark)),
+                    SetAttribute(Attribute::Reset),
+                    ResetColor,
+                    Print("|"),
+                    MoveTo(x, y + 1),
+                    Print("+---+")
+                )?;
+            }
+        }
+
+        let bottom = layout.bottom();
+        queue!(
+            out,
+            MoveTo(LEFT, bottom + 1),
+            Print("Arrow keys move, Enter or a click selects a cell, Q quits."),
+            MoveTo(LEFT, bottom + 2),
+            Print(message.unwrap_or_default())
+        )?;
+
+        let (width, height) = terminal::size()?;
+        let status = format!(" {}", self.status());
+        queue!(
+            out,
+            MoveTo(0, height.saturating_sub(1).max(bottom + 3)),
+            SetAttribute(Attribute::Reverse),
+            Print(format!("{:width$}", status, width = width as usize)),
+            SetAttribute(Attribute::Reset)
+        )?;
+        out.flush()?;
+        Ok(())
+    }
+
+    /// Adds the game to the scoreboard and the profiles once the last move has been made,
+    /// returning anything the players should be told about their profiles.
+    fn finish_if_over(&mut self) -> Option<String> {
+        if self.board.outcome() == Outcome::InProgress {
+            return None;
+        }
+        let notes = self.finish_game();
+        if notes.is_empty() {
+            None
+        } else {
+            Some(notes.join(" "))
+        }
+    }
+
+    /// Clears the board for another game, whose starter is picked by the [`StartPolicy`](crate::StartPolicy).
+    ///
+    /// There is no prompt on the full-screen board, so [`StartPolicy::Ask`](crate::StartPolicy::Ask)
+    /// picks at random.
+    fn new_game(&mut self) {
+        self.current_player = self
+            .next_start_player()
+            .unwrap_or_else(|| self.random_player());
+        self.starter = Some(self.current_player);
+        self.board = self.board.cleared();
+        self.history.clear();
+        self.undone.clear();
+    }
+}
+
+// Public-Crate block
+impl<R: BufRead, W: Write> Game<R, W> {
+    /// Plays the game in a full-screen terminal interface until the players quit.
+    pub(crate) fn run_full_screen(&mut self) -> Result<(), Error> {
+        let mut screen = Screen::enter()?;
+        let mut cursor = (self.board.dimension() / 2, self.board.dimension() / 2);
+        let mut message: Option<String> = None;
+
+        loop {
+            let layout = Layout::new(&self.board);
+            self.draw(&mut screen, cursor, message.take().as_deref())?;
+            let in_progress = self.board.outcome() == Outcome::InProgress;
+            let human = self.controller(self.current_player) == Controller::Human;
+            if in_progress && !human && !event::poll(COMPUTER_DELAY)? {
+                self.select_cell()?;
+                message = self.finish_if_over();
+                continue;
+            }
+
+            let mut chosen = None;
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                    KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char('n') | KeyCode::Char('N')
+                        if !in_progress && self.next_game() != NextGame::Stop =>
+                    {
+                        self.new_game()
+                    }
+                    KeyCode::Up => cursor.0 = cursor.0.saturating_sub(1),
+                    KeyCode::Down => cursor.0 = (cursor.0 + 1).min(layout.dimension - 1),
+                    KeyCode::Left => cursor.1 = cursor.1.saturating_sub(1),
+                    KeyCode::Right => cursor.1 = (cursor.1 + 1).min(layout.dimension - 1),
+                    KeyCode::Enter | KeyCode::Char(' ') => {
+                        chosen = Some(Ok(self.board.index_at(cursor.0, cursor.1)))
+                    }
+                    KeyCode::Char(c) if c.is_ascii_digit() => {
+                        chosen = Some(self.board.parse_cell(&c.to_string()))
+                    }
+                    _ => (),
+                },
+                Event::Mouse(MouseEvent {
+                    kind: MouseEventKind::Down(MouseButton::Left),
+                    column,
+                    row,
+                    ..
+                }) => {
+                    if let Some(clicked) = layout.cell_at(column, row) {
+                        cursor = clicked;
+                        chosen = Some(Ok(self.board.index_at(clicked.0, clicked.1)));
+                    }
+                }
+                _ => (),
+            }
+
+            if !in_progress || !human {
+                continue;
+            }
+            match chosen.map(|cell| cell.and_then(|cell| self.make_move(cell))) {
+                Some(Ok(())) => {
+                    self.send_last_move()?;
+                    message = self.finish_if_over();
+                }
+                Some(Err(e)) => message = Some(format!("Sorry, {}.", e)),
+                None => (),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod layout_tests {
+    use super::super::Layout;
+    use crate::{Grid, Playfield, Ultimate};
+
+    #[test]
+    fn clicks_find_the_cell_under_them() {
+        let layout = Layout::new(&Grid::classic().into());
+        assert_eq!(Some((0, 0)), layout.cell_at(3, 3));
+        assert_eq!(Some((0, 0)), layout.cell_at(5, 3));
+        assert_eq!(Some((2, 2)), layout.cell_at(12, 7));
+        assert_eq!(None, layout.cell_at(2, 3));
+        assert_eq!(None, layout.cell_at(3, 4));
+        assert_eq!(9, layout.bottom());
+    }
+
+    #[test]
+    fn every_cell_can_be_clicked() {
+        for playfield in [
+            Playfield::from(Grid::new(5, 4).unwrap()),
+            Playfield::Ultimate(Ultimate::new()),
+        ] {
+            let layout = Layout::new(&playfield);
+            for row in 0..layout.dimension {
+                for column in 0..layout.dimension {
+                    let (x, y) = layout.origin(row, column);
+                    assert_eq!(Some((row, column)), layout.cell_at(x + 2, y));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sub_boards_are_spaced_apart() {
+        let layout = Layout::new(&Playfield::Ultimate(Ultimate::new()));
+        assert_eq!((16, 3), layout.origin(0, 3));
+        assert_eq!((2, 11), layout.origin(3, 0));
+    }
+
+    #[test]
+    fn rows_count_down_from_the_top() {
+        let classic = Playfield::from(Grid::classic());
+        assert_eq!("7", classic.cell_name(classic.index_at(0, 0)));
+        assert_eq!("3", classic.cell_name(classic.index_at(2, 2)));
+        let ultimate = Playfield::Ultimate(Ultimate::new());
+        assert_eq!("77", ultimate.cell_name(ultimate.index_at(0, 0)));
+        assert_eq!("53", ultimate.cell_name(ultimate.index_at(5, 5)));
+        assert_eq!("33", ultimate.cell_name(ultimate.index_at(8, 8)));
+    }
+}
+
+#[cfg(test)]
+mod status_tests {
+    use crate::{Game, NextGame, Options, Outcome};
+
+    /// Plays a game that X wins along the bottom row, with the given arguments added.
+    fn finished_game(variant: &str, start: &str, args: &[&str]) -> Game<&'static [u8], Vec<u8>> {
+        let mut all = vec![
+            "--variant",
+            variant,
+            "--start",
+            start,
+            "--opponent",
+            "human",
+        ];
+        all.extend_from_slice(args);
+        let options = Options::from_args(&all).unwrap();
+        let mut game = Game::new("X\n".as_bytes(), Vec::new(), &options).unwrap();
+        for cell in [0, 3, 1, 4, 2] {
+            game.make_move(cell).unwrap();
+        }
+        game
+    }
+
+    #[test]
+    fn status_names_the_winner() {
+        assert_eq!(
+            "Player X has won! Press N to play again or Q to quit.",
+            finished_game("classic", "X", &[]).status()
+        );
+    }
+
+    #[test]
+    fn misere_status_names_the_loser_first() {
+        assert_eq!(
+            "Player X has lost! Player O has won! Press N to play again or Q to quit.",
+            finished_game("misere", "X", &[]).status()
+        );
+    }
+
+    #[test]
+    fn profiles_name_the_players_and_report_their_ratings() {
+        let path = std::env::temp_dir().join(format!(
+            "tic_tac_toe_tui_profiles_test_{}.txt",
+            std::process::id()
+        ));
+        let path = path.to_str().unwrap();
+        let _ = std::fs::remove_file(path);
+        let args = [
+            "--profile-o",
+            "Alice",
+            "--profile-x",
+            "Bob",
+            "--profiles",
+            path,
+        ];
+        let mut game = finished_game("classic", "X", &args);
+        let message = game.finish_if_over();
+        let _ = std::fs::remove_file(path);
+        assert_eq!(
+            "Bob has won! Press N to play again or Q to quit.",
+            game.status()
+        );
+        assert_eq!(
+            Some("Alice is now rated 1484 (-16) Bob is now rated 1516 (+16)"),
+            message.as_deref()
+        );
+        game.new_game();
+        assert_eq!("Bob to move", game.status());
+    }
+
+    #[test]
+    fn new_game_picks_a_starter_without_asking() {
+        let mut game = finished_game("classic", "ask", &[]);
+        game.new_game();
+        assert_eq!(Outcome::InProgress, game.board.outcome());
+        assert_eq!(Some(game.current_player), game.starter);
+        assert!(game.history.is_empty());
+    }
+
+    #[test]
+    fn finished_games_are_scored() {
+        let mut game = finished_game("classic", "X", &["--best-of", "3"]);
+        game.finish_game();
+        assert_eq!(NextGame::Match, game.next_game());
+        assert_eq!(1, game.scoreboard.games());
+        assert_eq!(
+            "Player X has won! Press N for the next game of the match or Q to quit.",
+            game.status()
+        );
+    }
+
+    #[test]
+    fn decided_matches_stop() {
+        let mut game = finished_game("classic", "X", &["--best-of", "1"]);
+        game.finish_game();
+        assert_eq!(NextGame::Stop, game.next_game());
+        assert_eq!(
+            "Player X has won! Player X has won the match! Press Q to quit.",
+            game.status()
+        );
+        let mut game = finished_game("classic", "X", &["--no-replay"]);
+        game.finish_game();
+        assert_eq!(NextGame::Stop, game.next_game());
+        assert_eq!("Player X has won! Press Q to quit.", game.status());
+    }
+}
@@ -0,0 +1,202 @@
+//! Provides functionality for the [Ultimate] struct
+//!
+//! Ultimate Tic-Tac-Toe is played on a 3×3 grid of 3×3 [Board]s. The cell a
+//! player picks decides the sub-board their opponent must play in next. When
+//! that sub-board is already finished, the opponent may play in any sub-board.
+//!
+//! Winning a sub-board claims the matching cell of the meta-board, which is
+//! itself a [Board], so the game is won with the usual [`WinPattern`]s.
+//!
+//! [`WinPattern`]: crate::WinPattern
+
+use crate::bit_patterns::{Cell, WinPattern};
+use crate::{Board, Difficulty, Error, Outcome, Player, Rng, Ultimate};
+
+mod ultimate_display;
+
+/// Gets the position of a cell in [`Cell::ALL`].
+fn cell_index(cell: Cell) -> usize {
+    cell.number() as usize - 1
+}
+
+// Private block
+impl Ultimate {
+    /// Checks if a sub-board can still be played in.
+    fn is_open(&self, board: Cell) -> bool {
+        self.boards[cell_index(board)].outcome() == Outcome::InProgress
+    }
+
+    /// Gets the sub-boards the next move may be played in.
+    fn open_boards(&self) -> Vec<Cell> {
+        match self.forced {
+            Some(board) if self.is_open(board) => vec![board],
+            _ => Cell::ALL
+                .iter()
+                .copied()
+                .filter(|board| self.is_open(*board))
+                .collect(),
+        }
+    }
+
+    /// Lists the moves that would win a sub-board for the given player.
+    fn moves_winning_a_board(&self, player: Player) -> Vec<(Cell, Cell)> {
+        self.legal_moves()
+            .into_iter()
+            .filter(|(board, cell)| {
+                let mut next = self.boards[cell_index(*board)];
+                next.set_cell(player, *cell);
+                next.winner() == Some(player)
+            })
+            .collect()
+    }
+}
+
+// Public block
+impl Ultimate {
+    /// Creates an empty game of Ultimate Tic-Tac-Toe.
+    pub const fn new() -> Self {
+        Self {
+            boards: [Board::new(); 9],
+            meta: Board::new(),
+            forced: None,
+        }
+    }
+
+    /// Gets the sub-board at the given position.
+    pub fn board(&self, board: Cell) -> &Board {
+        &self.boards[cell_index(board)]
+    }
+
+    /// Gets the meta-board, where each cell is claimed by winning its sub-board.
+    pub const fn meta(&self) -> &Board {
+        &self.meta
+    }
+
+    /// Gets the sub-board the next move must be played in, if the previous move decided it.
+    pub fn forced_board(&self) -> Option<Cell> {
+        self.forced.filter(|board| self.is_open(*board))
+    }
+
+    /// Lists every legal move as a pair of sub-board and cell.
+    ///
+    /// Once the game is over there are no legal moves.
+    pub fn legal_moves(&self) -> Vec<(Cell, Cell)> {
+        if self.outcome() != Outcome::InProgress {
+            return Vec::new();
+        }
+        self.open_boards()
+            .into_iter()
+            .flat_map(|board| {
+                self.board(board)
+                    .legal_moves()
+                    .into_iter()
+                    .map(move |cell| (board, cell))
+            })
+            .collect()
+    }
+
+    /// Sets a cell of a sub-board for a given player, if the move is legal.
+    pub fn apply_move(&mut self, player: Player, board: Cell, cell: Cell) -> Result<(), Error> {
+        if self.outcome() != Outcome::InProgress {
+            return Err(Error::GameOver);
+        }
+        if !self.is_open(board) {
+            return Err(Error::SubBoardDecided(board));
+        }
+        if let Some(forced) = self.forced_board() {
+            if forced != board {
+                return Err(Error::WrongSubBoard(forced));
+            }
+        }
+        let sub_board = &mut self.boards[cell_index(board)];
+        sub_board.apply_move(player, cell)?;
+        if sub_board.winner() == Some(player) {
+            self.meta.set_cell(player, board);
+        }
+        self.forced = Some(cell);
+        Ok(())
+    }
+
+    /// Gets the player who has won the meta-board, if any.
+    pub fn winner(&self) -> Option<Player> {
+        self.meta.winner()
+    }
+
+    /// Lists every [`WinPattern`] completed on the meta-board, and who completed it.
+    pub fn winning_patterns(&self) -> Vec<(Player, WinPattern)> {
+        self.meta.winning_patterns()
+    }
+
+    /// Gets the [Outcome] of the game as it currently stands.
+    pub fn outcome(&self) -> Outcome {
+        match self.winner() {
+            Some(player) => Outcome::Win(player),
+            None if Cell::ALL.iter().all(|board| !self.is_open(*board)) => Outcome::Draw,
+            None => Outcome::InProgress,
+        }
+    }
+
+    /// Picks a move for the given player at the given [Difficulty].
+    ///
+    /// [`Difficulty::Easy`] plays randomly. Every other difficulty wins the game when
+    /// it can, then wins a sub-board, then stops the opponent winning a sub-board.
+    /// Returns [None] if the game is already over.
+    pub fn computer_move(
+        &self,
+        player: Player,
+        difficulty: Difficulty,
+        rng: &mut Rng,
+    ) -> Option<(Cell, Cell)> {
+        let legal_moves = self.legal_moves();
+        if difficulty == Difficulty::Easy {
+            return rng.choose(&legal_moves).copied();
+        }
+
+        let winning_moves = self.moves_winning_a_board(player);
+        let game_winning_moves: Vec<(Cell, Cell)> = winning_moves
+            .iter()
+            .copied()
+            .filter(|(board, cell)| {
+                let mut next = self.clone();
+                next.apply_move(player, *board, *cell).is_ok() && next.winner() == Some(player)
+            })
+            .collect();
+        let blocking_moves = self.moves_winning_a_board(player.opponent());
+
+        [
+            game_winning_moves,
+            winning_moves,
+            blocking_moves,
+            legal_moves,
+        ]
+        .iter()
+        .find(|moves| !moves.is_empty())
+        .and_then(|moves| rng.choose(moves).copied())
+    }
+}
+
+// Public-Crate block
+impl Ultimate {
+    /// Lists the sub-board and cell of every cell that won a sub-board in a completed line of the meta-board.
+    pub(crate) fn winning_line(&self) -> Vec<(Cell, Cell)> {
+        self.meta
+            .winning_line()
+            .into_iter()
+            .flat_map(|board| {
+                self.board(board)
+                    .winning_line()
+                    .into_iter()
+                    .map(move |cell| (board, cell))
+            })
+            .collect()
+    }
+}
+
+impl Default for Ultimate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests;
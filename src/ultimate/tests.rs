@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod ultimate_tests {
+    use crate::bit_patterns::{Cell, WinPattern};
+    use crate::{Board, Difficulty, Outcome, Player, Rng, Ultimate};
+
+    /// Builds a sub-board where the given player has won the top row.
+    fn won_board(player: Player) -> Board {
+        let mut board = Board::new();
+        board.set_cell(player, Cell::TopLeft);
+        board.set_cell(player, Cell::TopCentre);
+        board.set_cell(player, Cell::TopRight);
+        board
+    }
+
+    #[test]
+    fn first_move_can_be_anywhere() {
+        assert_eq!(81, Ultimate::new().legal_moves().len());
+        assert_eq!(None, Ultimate::new().forced_board());
+    }
+
+    #[test]
+    fn move_sends_opponent_to_matching_board() {
+        let mut game = Ultimate::new();
+        game.apply_move(Player::X, Cell::Centre, Cell::TopLeft)
+            .unwrap();
+        assert_eq!(Some(Cell::TopLeft), game.forced_board());
+        assert!(game
+            .legal_moves()
+            .iter()
+            .all(|(board, _)| *board == Cell::TopLeft));
+        assert_eq!(9, game.legal_moves().len());
+        assert!(game
+            .apply_move(Player::O, Cell::Centre, Cell::Centre)
+            .is_err());
+        assert!(game
+            .apply_move(Player::O, Cell::TopLeft, Cell::Centre)
+            .is_ok());
+        assert_eq!(
+            Some(Player::X),
+            game.board(Cell::Centre).owner(Cell::TopLeft)
+        );
+        assert_eq!(
+            Some(Player::O),
+            game.board(Cell::TopLeft).owner(Cell::Centre)
+        );
+    }
+
+    #[test]
+    fn winning_a_board_claims_the_meta_cell() {
+        let mut game = Ultimate::new();
+        game.boards[0].set_cell(Player::O, Cell::BottomLeft);
+        game.boards[0].set_cell(Player::O, Cell::BottomCentre);
+        game.apply_move(Player::O, Cell::BottomLeft, Cell::BottomRight)
+            .unwrap();
+        assert_eq!(Some(Player::O), game.meta().owner(Cell::BottomLeft));
+        assert_eq!(Outcome::InProgress, game.outcome());
+    }
+
+    #[test]
+    fn sent_to_a_decided_board_plays_anywhere() {
+        let mut game = Ultimate::new();
+        game.boards[4] = won_board(Player::X);
+        game.meta.set_cell(Player::X, Cell::Centre);
+        game.apply_move(Player::O, Cell::TopLeft, Cell::Centre)
+            .unwrap();
+        assert_eq!(None, game.forced_board());
+        assert_eq!(71, game.legal_moves().len());
+        assert!(game
+            .apply_move(Player::X, Cell::Centre, Cell::BottomLeft)
+            .is_err());
+    }
+
+    #[test]
+    fn three_boards_in_a_row_win_the_game() {
+        let mut game = Ultimate::new();
+        for board in &[Cell::BottomLeft, Cell::BottomCentre] {
+            game.boards[board.number() as usize - 1] = won_board(Player::X);
+            game.meta.set_cell(Player::X, *board);
+        }
+        let last = &mut game.boards[2];
+        last.set_cell(Player::X, Cell::TopLeft);
+        last.set_cell(Player::X, Cell::TopCentre);
+        game.apply_move(Player::X, Cell::BottomRight, Cell::TopRight)
+            .unwrap();
+        assert_eq!(Outcome::Win(Player::X), game.outcome());
+        assert!(game.legal_moves().is_empty());
+    }
+
+    #[test]
+    fn computer_finishes_games_at_every_difficulty() {
+        let mut rng = Rng::new(21);
+        for difficulty in &[
+            Difficulty::Easy,
+            Difficulty::Medium,
+            Difficulty::Hard,
+            Difficulty::Perfect,
+        ] {
+            let mut game = Ultimate::new();
+            let mut player = Player::X;
+            while let Some((board, cell)) = game.computer_move(player, *difficulty, &mut rng) {
+                game.apply_move(player, board, cell).unwrap();
+                player.swap();
+            }
+            assert_ne!(Outcome::InProgress, game.outcome());
+        }
+    }
+
+    #[test]
+    fn computer_wins_the_game_when_it_can() {
+        let mut game = Ultimate::new();
+        for board in &[Cell::BottomLeft, Cell::BottomCentre] {
+            game.boards[board.number() as usize - 1] = won_board(Player::O);
+            game.meta.set_cell(Player::O, *board);
+        }
+        game.boards[2].set_cell(Player::O, Cell::Centre);
+        game.boards[2].set_cell(Player::O, Cell::TopRight);
+        game.forced = Some(Cell::BottomRight);
+        let mut rng = Rng::new(4);
+        assert_eq!(
+            Some((Cell::BottomRight, Cell::BottomLeft)),
+            game.computer_move(Player::O, Difficulty::Medium, &mut rng)
+        );
+    }
+
+    #[test]
+    fn winning_line_lists_the_cells_that_won_each_board() {
+        let mut game = Ultimate::new();
+        for board in &[Cell::BottomLeft, Cell::BottomCentre, Cell::BottomRight] {
+            game.boards[board.number() as usize - 1] = won_board(Player::X);
+            game.meta.set_cell(Player::X, *board);
+        }
+        assert_eq!(
+            vec![(Player::X, WinPattern::BottomRow)],
+            game.winning_patterns()
+        );
+        let line = game.winning_line();
+        assert_eq!(9, line.len());
+        assert!(format!("{:#}", game).contains("|[X]|[X]|[X]| |[X]|[X]|[X]| |[X]|[X]|[X]|\n"));
+        assert_eq!((Cell::BottomLeft, Cell::TopLeft), line[0]);
+        assert_eq!((Cell::BottomRight, Cell::TopRight), line[8]);
+    }
+
+    #[test]
+    fn display_shows_boards_top_to_bottom() {
+        let mut game = Ultimate::new();
+        game.apply_move(Player::X, Cell::TopLeft, Cell::BottomRight)
+            .unwrap();
+        let display = game.to_string();
+        let lines: Vec<&str> = display.lines().collect();
+        assert_eq!("------------- ------------- -------------", lines[0]);
+        assert_eq!("| E | E | X | | E | E | E | | E | E | E |", lines[5]);
+        assert!(display.ends_with("The next move must be in sub-board 3\n"));
+    }
+}
@@ -0,0 +1,69 @@
+//! Handles how an Ultimate game is displayed to the players.
+//!
+//! The alternate format, `{:#}`, draws the cells that won the game in brackets.
+
+use crate::bit_patterns::Cell;
+use crate::{Player, Ultimate};
+
+fn draw_top_line() -> String {
+    ["-------------"; 3].join(" ") + "\n"
+}
+
+fn mark(owner: Option<Player>) -> char {
+    match owner {
+        Some(Player::X) => 'X',
+        Some(Player::O) => 'O',
+        None => 'E',
+    }
+}
+
+fn draw_cell(mark: char, highlighted: bool) -> String {
+    if highlighted {
+        format!("[{}]", mark)
+    } else {
+        format!(" {} ", mark)
+    }
+}
+
+impl std::fmt::Display for Ultimate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut output_string = String::new();
+        let winning_line = if f.alternate() {
+            self.winning_line()
+        } else {
+            Vec::new()
+        };
+
+        // Sub-boards and their cells are both numbered bottom-to-top, so rows are drawn in reverse.
+        for board_row in Cell::ALL.chunks(3).rev() {
+            output_string += &draw_top_line();
+            for cell_row in Cell::ALL.chunks(3).rev() {
+                let line: Vec<String> = board_row
+                    .iter()
+                    .map(|board| {
+                        let marks: Vec<String> = cell_row
+                            .iter()
+                            .map(|cell| {
+                                draw_cell(
+                                    mark(self.board(*board).owner(*cell)),
+                                    winning_line.contains(&(*board, *cell)),
+                                )
+                            })
+                            .collect();
+                        format!("|{}|", marks.join("|"))
+                    })
+                    .collect();
+                output_string += &line.join(" ");
+                output_string += "\n";
+                output_string += &draw_top_line();
+            }
+            output_string += "\n";
+        }
+
+        if let Some(board) = self.forced_board() {
+            output_string += &format!("The next move must be in sub-board {}\n", board);
+        }
+
+        write!(f, "{}", output_string)
+    }
+}